@@ -0,0 +1,68 @@
+// Copyright John Nunley, 2022.
+//
+// This software is distributed under the Boost Software License Version 1.0 and the Apache
+// 2.0 License, at your option. See the `LICENSE-BOOST` and `LICENSE-APACHE` files in the
+// root of this repository for the full text of the licenses.
+//
+// --------------------------------------------------------------------------------------------
+//
+//  Distributed under the Boost Software License, Version 1.0.
+//    (See accompanying file LICENSE-BOOST or copy at
+//        https://www.boost.org/LICENSE_1_0.txt)
+//
+// --------------------------------------------------------------------------------------------
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Throughput of tight arithmetic loops over `Double<f32>`.
+
+use breadsimd::Double;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const LEN: usize = 1024;
+
+fn inputs() -> Vec<Double<f32>> {
+    (0..LEN)
+        .map(|i| Double::new([i as f32, (LEN - i) as f32]))
+        .collect()
+}
+
+fn add_loop(c: &mut Criterion) {
+    let data = inputs();
+    c.bench_function("double_f32_add", |b| {
+        b.iter(|| {
+            let mut acc = Double::splat(0.0f32);
+            for &x in black_box(&data) {
+                acc += x;
+            }
+            acc
+        })
+    });
+}
+
+fn mul_loop(c: &mut Criterion) {
+    let data = inputs();
+    let scale = Double::new([0.5f32, 2.0]);
+    c.bench_function("double_f32_mul", |b| {
+        b.iter(|| {
+            let mut acc = Double::splat(1.0f32);
+            for &x in black_box(&data) {
+                acc = acc * scale + x;
+            }
+            acc
+        })
+    });
+}
+
+criterion_group!(benches, add_loop, mul_loop);
+criterion_main!(benches);
@@ -0,0 +1,99 @@
+// Copyright John Nunley, 2022.
+//
+// This software is distributed under the Boost Software License Version 1.0 and the Apache
+// 2.0 License, at your option. See the `LICENSE-BOOST` and `LICENSE-APACHE` files in the
+// root of this repository for the full text of the licenses.
+//
+// --------------------------------------------------------------------------------------------
+//
+//  Distributed under the Boost Software License, Version 1.0.
+//    (See accompanying file LICENSE-BOOST or copy at
+//        https://www.boost.org/LICENSE_1_0.txt)
+//
+// --------------------------------------------------------------------------------------------
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Throughput of math functions on `Quad<f32>`.
+//!
+//! On x86 with the `nightly` feature, build with `-C target-feature=+sse4.1` to compare the
+//! dedicated rounding instructions against the fallback.
+
+use breadsimd::Quad;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const LEN: usize = 1024;
+
+fn inputs() -> Vec<Quad<f32>> {
+    (0..LEN)
+        .map(|i| Quad::splat(i as f32 * 0.37 - 150.0) + Quad::new([0.0, 0.25, 0.5, 0.75]))
+        .collect()
+}
+
+fn rounding(c: &mut Criterion) {
+    let data = inputs();
+    let mut group = c.benchmark_group("quad_f32");
+
+    group.bench_function("floor", |b| {
+        b.iter(|| {
+            black_box(&data)
+                .iter()
+                .map(|x| x.floor())
+                .sum::<Quad<f32>>()
+        })
+    });
+    group.bench_function("ceil", |b| {
+        b.iter(|| black_box(&data).iter().map(|x| x.ceil()).sum::<Quad<f32>>())
+    });
+    group.bench_function("trunc", |b| {
+        b.iter(|| {
+            black_box(&data)
+                .iter()
+                .map(|x| x.trunc())
+                .sum::<Quad<f32>>()
+        })
+    });
+
+    group.finish();
+}
+
+fn powf(c: &mut Criterion) {
+    let data: Vec<Quad<f32>> = inputs()
+        .into_iter()
+        .map(|x| x.abs() + Quad::splat(1.0))
+        .collect();
+    let e = Quad::new([0.5f32, 2.2, -1.3, 4.0]);
+    let mut group = c.benchmark_group("quad_f32");
+
+    group.bench_function("powf_lanes", |b| {
+        b.iter(|| {
+            black_box(&data)
+                .iter()
+                .map(|x| Quad::from_fn(|i| x[i].powf(e[i])))
+                .sum::<Quad<f32>>()
+        })
+    });
+    group.bench_function("powf_fast", |b| {
+        b.iter(|| {
+            black_box(&data)
+                .iter()
+                .map(|x| x.powf_fast(e))
+                .sum::<Quad<f32>>()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, rounding, powf);
+criterion_main!(benches);
@@ -0,0 +1,68 @@
+// Copyright John Nunley, 2022.
+//
+// This software is distributed under the Boost Software License Version 1.0 and the Apache
+// 2.0 License, at your option. See the `LICENSE-BOOST` and `LICENSE-APACHE` files in the
+// root of this repository for the full text of the licenses.
+//
+// --------------------------------------------------------------------------------------------
+//
+//  Distributed under the Boost Software License, Version 1.0.
+//    (See accompanying file LICENSE-BOOST or copy at
+//        https://www.boost.org/LICENSE_1_0.txt)
+//
+// --------------------------------------------------------------------------------------------
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Throughput of bit counting on `Quad<u32>`.
+//!
+//! With the `nightly` feature, `count_ones` uses a vectorized SWAR popcount; otherwise it
+//! counts each lane separately, the same as the baseline here.
+
+use breadsimd::Quad;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const LEN: usize = 1024;
+
+fn inputs() -> Vec<Quad<u32>> {
+    (0..LEN as u32)
+        .map(|i| Quad::new([i, i.wrapping_mul(0x9e37_79b9), !i, i << 16]))
+        .collect()
+}
+
+fn count_ones(c: &mut Criterion) {
+    let data = inputs();
+    let mut group = c.benchmark_group("quad_u32");
+
+    group.bench_function("count_ones", |b| {
+        b.iter(|| {
+            black_box(&data)
+                .iter()
+                .map(|x| x.count_ones())
+                .sum::<Quad<u32>>()
+        })
+    });
+    group.bench_function("count_ones_lanes", |b| {
+        b.iter(|| {
+            black_box(&data)
+                .iter()
+                .map(|x| Quad::from_fn(|i| x[i].count_ones()))
+                .sum::<Quad<u32>>()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, count_ones);
+criterion_main!(benches);
@@ -67,6 +67,15 @@
 //! assert_eq!(b, Double::new([4, 6]));
 //! ```
 //!
+//! ## Integer overflow
+//!
+//! The arithmetic operators on integer lanes follow the scalar operators on the portable
+//! implementation, panicking on overflow when debug assertions are enabled. However, lanes
+//! that are optimized using SIMD always wrap around on overflow, regardless of build
+//! settings. Since this is most likely to come up with narrow types like `u8` and `i16`,
+//! code that may overflow should use lanes of [`Wrapping`](core::num::Wrapping) or methods
+//! like [`wrapping_neg`](Quad::wrapping_neg) so that it behaves the same everywhere.
+//!
 //! # Features
 //!
 //! This crate has an `std` feature enabled by default, which enables the `std` library.
@@ -110,12 +119,16 @@ cfg_if::cfg_if! {
     }
 }
 
+use core::array::TryFromSliceError;
+use core::convert::TryFrom;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::iter::{Product, Sum};
+use core::mem;
 use core::ops;
 
 use num_traits::real::Real;
-use num_traits::Signed;
+use num_traits::{CheckedDiv, CheckedMul, PrimInt, Signed, WrappingNeg};
 
 /// A set of two values that may be SIMD optimized.
 ///
@@ -127,7 +140,7 @@ pub struct Double<T: Copy>(imp::Double<T>);
 /// Two booleans that are the result of a comparison.
 /// 
 /// This type may result from packed comparisons on [`Double`].
-#[derive(Copy, Clone, PartialEq, Default)]
+#[derive(Copy, Clone, Default)]
 #[repr(transparent)]
 pub struct DoubleMask<T: Copy>(imp::DoubleMask<T>);
 
@@ -141,10 +154,134 @@ pub struct Quad<T: Copy>(imp::Quad<T>);
 /// Four booleans that are the result of a comparison.
 /// 
 /// This type may result from packed comparisons on [`Quad`].
-#[derive(Copy, Clone, PartialEq, Default)]
+#[derive(Copy, Clone, Default)]
 #[repr(transparent)]
 pub struct QuadMask<T: Copy>(imp::QuadMask<T>);
 
+/// A fixed-width set of values that may be SIMD optimized.
+///
+/// This trait is implemented by [`Double`] and [`Quad`], so that code can be written
+/// generically over the number of lanes. Arithmetic operations are available through the
+/// usual operator traits, which can be added as additional bounds.
+///
+/// ## Example
+///
+/// ```
+/// use breadsimd::{Double, Quad, SimdTuple};
+/// use core::ops::{Add, Div};
+///
+/// fn centroid<V>(a: V, b: V, c: V) -> V
+/// where
+///     V: SimdTuple<Lane = f32> + Add<Output = V> + Div<Output = V>,
+/// {
+///     (a + b + c) / V::splat(3.0)
+/// }
+///
+/// let double = centroid(
+///     Double::new([0.0, 0.0]),
+///     Double::new([3.0, 0.0]),
+///     Double::new([0.0, 3.0]),
+/// );
+/// assert_eq!(double, Double::new([1.0, 1.0]));
+///
+/// let quad = centroid(Quad::splat(1.0), Quad::splat(2.0), Quad::splat(3.0));
+/// assert_eq!(quad, Quad::splat(2.0));
+/// ```
+pub trait SimdTuple: Copy + ops::Index<usize, Output = <Self as SimdTuple>::Lane> {
+    /// The type of a single lane.
+    type Lane: Copy;
+
+    /// The array type with the same number of lanes.
+    type Array: Copy + AsRef<[Self::Lane]> + AsMut<[Self::Lane]>;
+
+    /// The mask type produced by comparisons.
+    type Mask: Copy;
+
+    /// The number of lanes.
+    const LANES: usize;
+
+    /// Create a new value from an array.
+    fn new(array: Self::Array) -> Self;
+
+    /// Create a new value populated with a single value in all lanes.
+    fn splat(value: Self::Lane) -> Self;
+
+    /// Get the underlying array.
+    fn into_inner(self) -> Self::Array;
+
+    /// Pick each lane from `if_true` where `mask` is set, or from `if_false` otherwise.
+    fn select(mask: Self::Mask, if_true: Self, if_false: Self) -> Self;
+}
+
+/// Pick each lane from `if_true` where `mask` is set, or from `if_false` otherwise.
+///
+/// This only moves lanes and never performs arithmetic on them, so the result is
+/// bit-for-bit identical regardless of whether the `nightly` feature is enabled or which
+/// backend is used for `T`.
+///
+/// ## Example
+///
+/// ```
+/// use breadsimd::{where_true, Quad, QuadMask};
+///
+/// let mask = QuadMask::new([true, false, false, true]);
+/// let picked = where_true(mask, Quad::splat(1), Quad::splat(2));
+/// assert_eq!(picked, Quad::new([1, 2, 2, 1]));
+/// ```
+#[inline]
+pub fn where_true<V: SimdTuple>(mask: V::Mask, if_true: V, if_false: V) -> V {
+    V::select(mask, if_true, if_false)
+}
+
+/// Iterate over each consecutive pair of `Double`s in a slice, combined into a `Quad`.
+///
+/// This is useful for treating a list of points as a list of line segments. A slice of `n`
+/// points yields `n - 1` segments, or none if it has fewer than two points.
+///
+/// ## Example
+///
+/// ```
+/// use breadsimd::{windows2, Double, Quad};
+///
+/// let points = [Double::new([0, 0]), Double::new([1, 2]), Double::new([3, 4])];
+/// let segments: Vec<_> = windows2(&points).collect();
+/// assert_eq!(segments, [Quad::new([0, 0, 1, 2]), Quad::new([1, 2, 3, 4])]);
+/// ```
+#[inline]
+pub fn windows2<T: Copy>(slice: &[Double<T>]) -> impl Iterator<Item = Quad<T>> + '_ {
+    slice
+        .windows(2)
+        .map(|pair| Quad::from_double(pair[0], pair[1]))
+}
+
+/// A lane type that can be summed into a wider type without overflowing.
+///
+/// This is used by [`Quad::reduce_sum_wide`] and [`Double::reduce_sum_wide`].
+pub trait WideningSum: Copy {
+    /// The wider type that lanes are summed into.
+    type Wide: Copy + From<Self> + ops::Add<Output = Self::Wide>;
+}
+
+macro_rules! widening_sum {
+    ($($ty:ty => $wide:ty),*) => {
+        $(
+            impl WideningSum for $ty {
+                type Wide = $wide;
+            }
+        )*
+    };
+}
+
+widening_sum! {
+    i8 => i32,
+    u8 => u32,
+    i16 => i32,
+    u16 => u32,
+    i32 => i64,
+    u32 => u64,
+    f32 => f64
+}
+
 macro_rules! implementation {
     (
         $gen:ident,
@@ -165,6 +302,159 @@ macro_rules! implementation {
         #[cfg(feature = "bytemuck")]
         unsafe impl<$gen: bytemuck::Pod> bytemuck::Pod for $name {}
 
+        #[cfg(feature = "bytemuck")]
+        impl<$gen: bytemuck::Pod> $name {
+            /// Reinterpret a slice of lanes as a slice of arrays.
+            ///
+            /// Trailing lanes that don't fill a whole array are left out of the result.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is not aligned for this type. This can only happen when the
+            /// `nightly` feature is enabled, since SIMD vectors may require more alignment
+            /// than their lanes.
+            #[inline]
+            pub fn cast_slice(slice: &[$gen]) -> &[Self] {
+                let len = slice.len() - slice.len() % $len;
+                bytemuck::cast_slice(&slice[..len])
+            }
+
+            /// Reinterpret a mutable slice of lanes as a mutable slice of arrays.
+            ///
+            /// Trailing lanes that don't fill a whole array are left out of the result.
+            ///
+            /// # Panics
+            ///
+            /// Panics under the same conditions as [`cast_slice`](Self::cast_slice).
+            #[inline]
+            pub fn cast_slice_mut(slice: &mut [$gen]) -> &mut [Self] {
+                let len = slice.len() - slice.len() % $len;
+                bytemuck::cast_slice_mut(&mut slice[..len])
+            }
+        }
+
+        // Archived as the equivalent array, which has the same layout as the portable
+        // representation.
+        #[cfg(feature = "rkyv")]
+        impl<$gen: Copy + rkyv::Archive> rkyv::Archive for $name {
+            type Archived = <[$gen; $len] as rkyv::Archive>::Archived;
+            type Resolver = <[$gen; $len] as rkyv::Archive>::Resolver;
+
+            #[inline]
+            unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+                self.into_inner().resolve(pos, resolver, out);
+            }
+        }
+
+        #[cfg(feature = "rkyv")]
+        impl<$gen, S> rkyv::Serialize<S> for $name
+        where
+            $gen: Copy + rkyv::Serialize<S>,
+            S: rkyv::Fallible + ?Sized,
+        {
+            #[inline]
+            fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+                self.into_inner().serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "rkyv")]
+        impl<$gen, D> rkyv::Deserialize<$name, D> for [rkyv::Archived<$gen>; $len]
+        where
+            $gen: Copy + rkyv::Archive,
+            rkyv::Archived<$gen>: rkyv::Deserialize<$gen, D>,
+            D: rkyv::Fallible + ?Sized,
+        {
+            #[inline]
+            fn deserialize(&self, deserializer: &mut D) -> Result<$name, D::Error> {
+                let array: [$gen; $len] = self.deserialize(deserializer)?;
+                Ok($self_ident::new(array))
+            }
+        }
+
+        // Serialized as the equivalent array.
+        #[cfg(feature = "serde")]
+        impl<$gen: Copy + serde::Serialize> serde::Serialize for $name {
+            #[inline]
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.into_inner().serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, $gen: Copy + serde::Deserialize<'de>> serde::Deserialize<'de> for $name {
+            #[inline]
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <[$gen; $len]>::deserialize(deserializer).map($self_ident::new)
+            }
+        }
+
+        // Masks are serialized as an array of booleans for human-readable formats, and as
+        // the compact bitmask from `as_u8` otherwise.
+        #[cfg(feature = "serde")]
+        impl<$gen: Copy> serde::Serialize for $mask_ident<$gen> {
+            #[inline]
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    self.into_inner().serialize(serializer)
+                } else {
+                    serializer.serialize_u8(self.as_u8())
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, $gen: Copy> serde::Deserialize<'de> for $mask_ident<$gen> {
+            #[inline]
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                if deserializer.is_human_readable() {
+                    return <[bool; $len]>::deserialize(deserializer).map($mask_ident::new);
+                }
+
+                let bits = u8::deserialize(deserializer)?;
+                if bits >> $len != 0 {
+                    return Err(serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Unsigned(u64::from(bits)),
+                        &"a bitmask with one bit per lane",
+                    ));
+                }
+
+                Ok($mask_ident::new([$(bits & (1 << $index) != 0),*]))
+            }
+        }
+
+        #[cfg(feature = "nightly")]
+        impl<$gen: core::simd::SimdElement> From<core::simd::Simd<$gen, $len>> for $name {
+            #[inline]
+            fn from(simd: core::simd::Simd<$gen, $len>) -> Self {
+                $self_ident::new(simd.to_array())
+            }
+        }
+
+        #[cfg(feature = "nightly")]
+        impl<$gen: core::simd::SimdElement> From<$name> for core::simd::Simd<$gen, $len> {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.as_simd()
+            }
+        }
+
+        #[cfg(feature = "nightly")]
+        impl<$gen: core::simd::SimdElement> $name {
+            /// Get the lanes of this array as a `core::simd` vector.
+            ///
+            /// This returns a copy, since lane types without SIMD support are stored as a
+            /// plain array that may not be aligned like a `Simd`. For supported lane types
+            /// the copy is free.
+            #[must_use]
+            #[inline]
+            pub fn as_simd(&self) -> core::simd::Simd<$gen, $len> {
+                core::simd::Simd::from_array(self.into_inner())
+            }
+        }
+
+        /// With the `nightly` feature, the alternate flag (`{:#?}`) prefixes the output with
+        /// `simd` or `naive`, depending on whether `T` is optimized using SIMD.
         impl<$gen: Copy + fmt::Debug> fmt::Debug for $name {
             #[inline]
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -172,6 +462,8 @@ macro_rules! implementation {
             }
         }
 
+        /// With the `nightly` feature, the alternate flag (`{:#?}`) prefixes the output with
+        /// `simd` or `naive`, depending on whether `T` is optimized using SIMD.
         impl<$gen: Copy> fmt::Debug for $mask_ident<$gen> {
             #[inline]
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -179,6 +471,23 @@ macro_rules! implementation {
             }
         }
 
+        // Masks only hold booleans, so these don't need any bounds on the lane type.
+        impl<$gen: Copy> PartialEq for $mask_ident<$gen> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<$gen: Copy> Eq for $mask_ident<$gen> {}
+
+        impl<$gen: Copy> Hash for $mask_ident<$gen> {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.hash(state);
+            }
+        }
+
         impl<$gen: Copy + ops::Add<Output = $gen>> ops::Add for $name {
             type Output = Self;
 
@@ -227,6 +536,10 @@ macro_rules! implementation {
             }
         }
 
+        /// Divides lane by lane.
+        ///
+        /// As with scalar integer division, this panics if any lane of an integer divisor is
+        /// zero. Use [`checked_div`](Self::checked_div) to handle this case instead.
         impl<$gen: Copy + ops::Div<Output = $gen>> ops::Div for $name {
             type Output = Self;
 
@@ -366,6 +679,10 @@ macro_rules! implementation {
             }
         }
 
+        /// Shifts lane by lane.
+        ///
+        /// As with scalar integers, each shift amount must be less than the bit width of `T`.
+        /// Use [`wrapping_shl`](Self::wrapping_shl) when shift amounts may be out of range.
         impl<$gen: Copy + ops::Shl<Output = $gen>> ops::Shl for $name {
             type Output = Self;
 
@@ -382,6 +699,10 @@ macro_rules! implementation {
             }
         }
 
+        /// Shifts lane by lane.
+        ///
+        /// As with scalar integers, each shift amount must be less than the bit width of `T`.
+        /// Use [`wrapping_shr`](Self::wrapping_shr) when shift amounts may be out of range.
         impl<$gen: Copy + ops::Shr<Output = $gen>> ops::Shr for $name {
             type Output = Self;
 
@@ -398,6 +719,13 @@ macro_rules! implementation {
             }
         }
 
+        impl<$gen: Copy + PartialEq> PartialEq<[$gen; $len]> for $name {
+            #[inline]
+            fn eq(&self, other: &[$gen; $len]) -> bool {
+                self.into_inner() == *other
+            }
+        }
+
         impl<$gen: Copy> From<[$gen; $len]> for $name {
             #[inline]
             fn from(array: [$gen; $len]) -> Self {
@@ -405,6 +733,23 @@ macro_rules! implementation {
             }
         }
 
+        impl<$gen: Copy> From<$name> for [$gen; $len] {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.into_inner()
+            }
+        }
+
+        /// Fails if the slice does not have exactly as many elements as there are lanes.
+        impl<'a, $gen: Copy> TryFrom<&'a [$gen]> for $name {
+            type Error = TryFromSliceError;
+
+            #[inline]
+            fn try_from(slice: &'a [$gen]) -> Result<Self, Self::Error> {
+                <[$gen; $len]>::try_from(slice).map($self_ident::new)
+            }
+        }
+
         impl<$gen: Copy> ops::Index<usize> for $name {
             type Output = $gen;
 
@@ -449,6 +794,17 @@ macro_rules! implementation {
             }
         }
 
+        impl<'a, $gen: Copy> IntoIterator for &'a mut $name {
+            type Item = &'a mut $gen;
+            type IntoIter = core::slice::IterMut<'a, $gen>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+
+        /// Sums arrays lane by lane. An empty iterator sums to all zeroes.
         impl<$gen: num_traits::Zero + Copy + ops::Add<Output = $gen>> Sum for $name {
             #[inline]
             fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
@@ -456,6 +812,7 @@ macro_rules! implementation {
             }
         }
 
+        /// Multiplies arrays lane by lane. An empty iterator multiplies to all ones.
         impl<$gen: num_traits::One + Copy + ops::Mul<Output = $gen>> Product for $name {
             #[inline]
             fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
@@ -463,195 +820,1300 @@ macro_rules! implementation {
             }
         }
 
+        impl<'a, $gen: num_traits::Zero + Copy + ops::Add<Output = $gen>> Sum<&'a Self> for $name {
+            #[inline]
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.cloned().sum()
+            }
+        }
+
+        impl<'a, $gen: num_traits::One + Copy + ops::Mul<Output = $gen>> Product<&'a Self>
+            for $name
+        {
+            #[inline]
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.cloned().product()
+            }
+        }
+
+        impl<$gen: Copy> SimdTuple for $name {
+            type Lane = $gen;
+            type Array = [$gen; $len];
+            type Mask = $mask_ident<$gen>;
+
+            const LANES: usize = $len;
+
+            #[inline]
+            fn new(array: [$gen; $len]) -> Self {
+                $self_ident::new(array)
+            }
+
+            #[inline]
+            fn splat(value: $gen) -> Self {
+                $self_ident::splat(value)
+            }
+
+            #[inline]
+            fn into_inner(self) -> [$gen; $len] {
+                $self_ident::into_inner(self)
+            }
+
+            #[inline]
+            fn select(mask: $mask_ident<$gen>, if_true: Self, if_false: Self) -> Self {
+                mask.select(if_true, if_false)
+            }
+        }
+
         impl<$gen: Copy> $name {
+            /// The number of lanes.
+            pub const LANES: usize = $len;
+
             /// Create a new array from an array.
             #[inline]
             pub fn new(array: [$gen; $len]) -> Self {
                 $self_ident(imp::$self_ident::new(array))
             }
 
+            /// Get the number of lanes, which is always [`LANES`](Self::LANES).
+            #[allow(clippy::len_without_is_empty, clippy::unused_self)]
+            #[must_use]
+            #[inline]
+            pub fn len(&self) -> usize {
+                $len
+            }
+
             /// Create a new array populated with a single value in all lanes.
             #[inline]
             pub fn splat(value: $gen) -> Self {
                 $self_ident(imp::$self_ident::splat(value))
             }
 
-            /// Get the underlying array.
+            /// Create a new array by calling a function with the index of each lane.
             #[inline]
-            pub fn into_inner(self) -> [$gen; $len] {
-                self.0.into_inner()
+            pub fn from_fn(mut f: impl FnMut(usize) -> $gen) -> Self {
+                $self_ident::new([$(f($index)),*])
             }
-        }
 
-        impl<$gen: Copy + Signed> $name {
-            /// Get the absolute value of each lane.
-            #[must_use]
+            /// Create a new array from the first lanes yielded by an iterator.
+            ///
+            /// Any lanes that the iterator runs out before reaching are set to `default`.
+            /// Elements past the number of lanes are not consumed.
             #[inline]
-            pub fn abs(self) -> Self {
-                $self_ident(self.0.abs())
+            pub fn from_iter_or(iter: impl IntoIterator<Item = $gen>, default: $gen) -> Self {
+                let mut iter = iter.into_iter();
+                $self_ident::from_fn(|_| iter.next().unwrap_or(default))
             }
-        }
 
-        impl<$gen: Copy + PartialEq> $name {
-            /// Compare the lanes of two arrays for equality.
-            #[must_use]
+            /// Create a new array from the first lanes of a slice, without checking its length.
+            ///
+            /// # Safety
+            ///
+            /// `slice` must contain at least as many elements as this array has lanes.
             #[inline]
-            pub fn packed_eq(self, other: Self) -> $mask_ident<$gen> {
-                $mask_ident(self.0.packed_eq(other.0))
+            pub unsafe fn from_slice_unchecked(slice: &[$gen]) -> Self {
+                debug_assert!(slice.len() >= $len);
+
+                // SAFETY: The caller guarantees that `slice` has at least `$len` elements, so the
+                // read stays in bounds, and `[T; N]` has the same alignment as `T`, so the slice
+                // pointer is suitably aligned for it.
+                $self_ident::new(core::ptr::read(slice.as_ptr().cast::<[$gen; $len]>()))
             }
 
-            /// Compare the lanes of two arrays for inequality.
-            #[must_use]
+            /// Get a reference to a lane, without bounds checking.
+            ///
+            /// # Safety
+            ///
+            /// `index` must be less than the number of lanes.
             #[inline]
-            pub fn packed_ne(self, other: Self) -> $mask_ident<$gen> {
-                $mask_ident(self.0.packed_ne(other.0))
+            pub unsafe fn get_unchecked(&self, index: usize) -> &$gen {
+                debug_assert!(index < $len);
+                let lanes: &[$gen] = self.as_ref();
+                lanes.get_unchecked(index)
             }
-        }
 
-        impl<$gen: Copy + PartialOrd> $name {
-            /// Compare the lanes of two arrays for less than.
-            #[must_use]
+            /// Get a mutable reference to a lane, without bounds checking.
+            ///
+            /// # Safety
+            ///
+            /// `index` must be less than the number of lanes.
             #[inline]
-            pub fn packed_lt(self, other: Self) -> $mask_ident<$gen> {
-                $mask_ident(self.0.packed_lt(other.0))
+            pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut $gen {
+                debug_assert!(index < $len);
+                let lanes: &mut [$gen] = self.as_mut();
+                lanes.get_unchecked_mut(index)
             }
 
-            /// Compare the lanes of two arrays for less than or equal.
+            /// Create a new array from the first lanes of a slice aligned for this type.
+            ///
+            /// When the `nightly` feature is enabled and `T` has a SIMD representation, this
+            /// reads the vector directly with an aligned load, which is faster than an unaligned
+            /// load on some platforms.
+            ///
+            /// # Safety
+            ///
+            /// `slice` must contain at least as many elements as this array has lanes, and its
+            /// start must be aligned to `align_of::<Self>()` bytes. This is the alignment of `T`
+            /// without SIMD, and the alignment of the SIMD vector with it (for instance, 16 bytes
+            /// for a `Quad<f32>`).
+            #[inline]
+            pub unsafe fn from_aligned(slice: &[$gen]) -> Self {
+                debug_assert!(slice.len() >= $len);
+                debug_assert_eq!(slice.as_ptr() as usize % mem::align_of::<Self>(), 0);
+
+                $self_ident(imp::$self_ident::load_aligned(slice.as_ptr()))
+            }
+
+            /// Store the lanes of this array into the start of a slice aligned for this type.
+            ///
+            /// # Safety
+            ///
+            /// `slice` must have the same length and alignment as required by
+            /// [`from_aligned`](Self::from_aligned).
+            #[inline]
+            pub unsafe fn store_aligned(self, slice: &mut [$gen]) {
+                debug_assert!(slice.len() >= $len);
+                debug_assert_eq!(slice.as_ptr() as usize % mem::align_of::<Self>(), 0);
+
+                self.0.store_aligned(slice.as_mut_ptr());
+            }
+
+            /// View an array as this type without copying it.
+            ///
+            /// Returns `None` if `array` is not aligned for this type. Without the `nightly`
+            /// feature this always succeeds, but SIMD vectors may require more alignment than
+            /// their lanes, so with it the result depends on where the array is stored.
             #[must_use]
             #[inline]
-            pub fn packed_le(self, other: Self) -> $mask_ident<$gen> {
-                $mask_ident(self.0.packed_le(other.0))
+            pub fn from_array_ref(array: &[$gen; $len]) -> Option<&Self> {
+                if array.as_ptr() as usize % mem::align_of::<Self>() != 0 {
+                    return None;
+                }
+
+                // SAFETY: This type is a transparent wrapper around either an array or a SIMD
+                // vector of the same size, and we've checked the alignment above.
+                Some(unsafe { &*(array as *const [$gen; $len]).cast::<Self>() })
             }
 
-            /// Compare the lanes of two arrays for greater than.
+            /// View a mutable array as this type without copying it.
+            ///
+            /// Returns `None` under the same conditions as
+            /// [`from_array_ref`](Self::from_array_ref).
             #[must_use]
             #[inline]
-            pub fn packed_gt(self, other: Self) -> $mask_ident<$gen> {
-                $mask_ident(self.0.packed_gt(other.0))
+            pub fn from_array_mut(array: &mut [$gen; $len]) -> Option<&mut Self> {
+                if array.as_ptr() as usize % mem::align_of::<Self>() != 0 {
+                    return None;
+                }
+
+                // SAFETY: See `from_array_ref`.
+                Some(unsafe { &mut *(array as *mut [$gen; $len]).cast::<Self>() })
             }
 
-            /// Compare the lanes of two arrays for greater than or equal.
+            /// Get the underlying array.
+            #[inline]
+            pub fn into_inner(self) -> [$gen; $len] {
+                self.0.into_inner()
+            }
+
+            /// Replace each lane with the result of calling a function on it.
+            #[inline]
+            pub fn apply(&mut self, mut f: impl FnMut($gen) -> $gen) {
+                let lanes: &mut [$gen; $len] = self.as_mut();
+                for lane in lanes {
+                    *lane = f(*lane);
+                }
+            }
+
+            /// Iterate over mutable references to each lane.
+            ///
+            /// This borrows the vector's storage directly, so mutations through the iterator are
+            /// visible in the vector. For types that are optimized using SIMD, this forces the
+            /// vector to be spilled to memory, so it may be slower than an equivalent vector
+            /// operation.
+            #[inline]
+            pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, $gen> {
+                let lanes: &mut [$gen] = self.as_mut();
+                lanes.iter_mut()
+            }
+
+            /// Get a copy of this array with every lane set to the first lane.
             #[must_use]
             #[inline]
-            pub fn packed_ge(self, other: Self) -> $mask_ident<$gen> {
-                $mask_ident(self.0.packed_ge(other.0))
+            pub fn broadcast_first(self) -> Self {
+                $self_ident::splat(self[0])
             }
-        }
 
-        impl<$gen: Copy + PartialOrd> $name {
-            /// Get the minimum of each lane.
+            /// Get a copy of this array with the lane at `index` replaced by `value`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is out of bounds.
             #[must_use]
             #[inline]
-            pub fn min(self, other: Self) -> Self {
-                $self_ident(self.0.min(other.0))
+            pub fn with_lane(mut self, index: usize, value: $gen) -> Self {
+                self[index] = value;
+                self
             }
 
-            /// Get the maximum of each lane.
+            /// Interleave the lanes of this array with another.
+            ///
+            /// The first array returned contains the interleaved low halves of both inputs,
+            /// and the second contains the interleaved high halves. For a `Quad`, this turns
+            /// `[a0, a1, a2, a3]` and `[b0, b1, b2, b3]` into `[a0, b0, a1, b1]` and
+            /// `[a2, b2, a3, b3]`.
             #[must_use]
             #[inline]
-            pub fn max(self, other: Self) -> Self {
-                $self_ident(self.0.max(other.0))
+            pub fn interleave(self, other: Self) -> (Self, Self) {
+                let (lo, hi) = self.0.interleave(other.0);
+                ($self_ident(lo), $self_ident(hi))
             }
 
-            /// Clamp these values to a certain range.
+            /// Split the lanes of this array and another into their even and odd lanes.
+            ///
+            /// This is the inverse of [`interleave`](Self::interleave).
             #[must_use]
             #[inline]
-            pub fn clamp(self, min: Self, max: Self) -> Self {
-                $self_ident(self.0.clamp(min.0, max.0))
+            pub fn deinterleave(self, other: Self) -> (Self, Self) {
+                let (even, odd) = self.0.deinterleave(other.0);
+                ($self_ident(even), $self_ident(odd))
             }
         }
 
-        impl<$gen: Copy + Real> $name {
-            /// Get the reciprocal of each lane.
+        impl<$gen: Copy + ops::Add<Output = $gen>> $name {
+            /// Add together every lane.
+            ///
+            /// The order in which floating point lanes are summed is unspecified. Use
+            /// [`reduce_sum_ordered`](Self::reduce_sum_ordered) if the result needs to be
+            /// reproducible across backends.
             #[must_use]
             #[inline]
-            pub fn recip(self) -> Self {
-                $self_ident(self.0.recip())
+            pub fn reduce_sum(self) -> $gen {
+                self.0.reduce_sum()
             }
 
-            /// Get the floor of each lane.
+            /// Add together every lane, strictly from the first lane to the last.
+            ///
+            /// Unlike [`reduce_sum`](Self::reduce_sum), this gives bit-identical floating point
+            /// results regardless of whether SIMD is used, at the cost of speed.
             #[must_use]
             #[inline]
-            pub fn floor(self) -> Self {
-                $self_ident(self.0.floor())
+            pub fn reduce_sum_ordered(self) -> $gen {
+                let lanes = self.into_inner();
+                let mut sum = lanes[0];
+                for lane in lanes.iter().skip(1) {
+                    sum = sum + *lane;
+                }
+                sum
             }
 
-            /// Get the ceiling of each lane.
+            /// Add together every lane.
+            ///
+            /// This is an alias for [`reduce_sum`](Self::reduce_sum). Not to be confused with
+            /// the `Sum` implementation, which adds several arrays together lane by lane.
             #[must_use]
             #[inline]
-            pub fn ceil(self) -> Self {
-                $self_ident(self.0.ceil())
+            pub fn sum_lanes(self) -> $gen {
+                self.reduce_sum()
             }
 
-            /// Round each lane to the nearest integer.
+            /// Get the running totals of the lanes.
+            ///
+            /// For a `Quad`, this is `[a, a + b, a + b + c, a + b + c + d]`.
             #[must_use]
             #[inline]
-            pub fn round(self) -> Self {
-                $self_ident(self.0.round())
+            pub fn prefix_sum(self) -> Self {
+                let mut lanes = self.into_inner();
+                for i in 1..$len {
+                    lanes[i] = lanes[i - 1] + lanes[i];
+                }
+                $self_ident::new(lanes)
             }
 
-            /// Get the square root of each lane.
+            /// Create a new array counting up from `start` by `step`.
+            ///
+            /// Each lane is the previous lane plus `step`. For floating point lanes, rounding
+            /// errors build up with each addition, so lane `k` may differ slightly from
+            /// `start + k * step`.
             #[must_use]
             #[inline]
-            pub fn sqrt(self) -> Self {
-                $self_ident(self.0.sqrt())
+            pub fn iota(start: $gen, step: $gen) -> Self {
+                let mut lane = start;
+                $self_ident::from_fn(|i| {
+                    if i > 0 {
+                        lane = lane + step;
+                    }
+                    lane
+                })
             }
-        }
 
-        impl<$gen: Copy> $mask_ident<$gen> {
-            /// Create a new mask from an array.
+            /// Add `other` to the lanes where `mask` is set, leaving the other lanes unchanged.
             #[must_use]
             #[inline]
-            pub fn new(array: [bool; $len]) -> Self {
-                $mask_ident(imp::$mask_ident::new(array))
+            pub fn add_where(self, other: Self, mask: $mask_ident<$gen>) -> Self {
+                mask.select(self + other, self)
             }
+        }
 
-            /// Create a new mask populated with a single value in all lanes.
+        impl<$gen: Copy + ops::Sub<Output = $gen>> $name {
+            /// Subtract `other` from the lanes where `mask` is set, leaving the other lanes
+            /// unchanged.
             #[must_use]
             #[inline]
-            pub fn splat(value: bool) -> Self {
-                $mask_ident(imp::$mask_ident::splat(value))
+            pub fn sub_where(self, other: Self, mask: $mask_ident<$gen>) -> Self {
+                mask.select(self - other, self)
             }
+        }
 
-            /// Get the underlying array.
+        impl<$gen: Copy + ops::Mul<Output = $gen>> $name {
+            /// Multiply together every lane.
+            ///
+            /// The order in which floating point lanes are multiplied is unspecified.
             #[must_use]
             #[inline]
-            pub fn into_inner(self) -> [bool; $len] {
-                self.0.into_inner()
+            pub fn reduce_product(self) -> $gen {
+                self.0.reduce_product()
             }
 
-            /// Tell if all lanes are true.
+            /// Multiply the lanes where `mask` is set by `other`, leaving the other lanes
+            /// unchanged.
             #[must_use]
             #[inline]
-            pub fn all(self) -> bool {
-                self.0.all()
+            pub fn mul_where(self, other: Self, mask: $mask_ident<$gen>) -> Self {
+                mask.select(self * other, self)
             }
 
-            /// Tell if any lanes are true.
+            /// Square each lane.
             #[must_use]
             #[inline]
-            pub fn any(self) -> bool {
-                self.0.any()
+            pub fn square(self) -> Self {
+                self * self
             }
 
-            /// Test if a specific lane is true.
+            /// Cube each lane.
             #[must_use]
             #[inline]
-            pub fn test(self, index: usize) -> bool {
-                self.0.test(index)
+            pub fn cube(self) -> Self {
+                self * self * self
             }
 
-            /// Set a specific lane to a value.
+            /// Multiply lane by lane.
+            ///
+            /// This is the same as `self * other`, for call sites where a name reads better.
+            #[must_use]
             #[inline]
-            pub fn set(&mut self, index: usize, value: bool) {
-                self.0.set(index, value);
+            pub fn mul_lanes(self, other: Self) -> Self {
+                self * other
             }
         }
-    };
-}
 
-implementation! {
-    T,
+        impl<$gen: Copy + ops::Add<Output = $gen> + ops::Mul<Output = $gen>> $name {
+            /// Get the dot product of this and another array, along with the per-lane
+            /// products that were summed to produce it.
+            #[must_use]
+            #[inline]
+            pub fn dot_with_products(self, other: Self) -> ($gen, Self) {
+                let products = self * other;
+                (products.reduce_sum(), products)
+            }
+        }
+
+        impl<$gen: WideningSum> $name {
+            /// Add together every lane, after converting them to a wider type.
+            ///
+            /// Unlike [`reduce_sum`](Self::reduce_sum), this can't overflow for integer lanes.
+            #[must_use]
+            #[inline]
+            pub fn reduce_sum_wide(self) -> $gen::Wide {
+                let lanes = self.into_inner();
+                let mut sum = $gen::Wide::from(lanes[0]);
+                for &lane in lanes.iter().skip(1) {
+                    sum = sum + $gen::Wide::from(lane);
+                }
+                sum
+            }
+        }
+
+        impl<$gen: Copy + num_traits::Zero> $name {
+            /// Create a new array with every lane set to zero.
+            #[must_use]
+            #[inline]
+            pub fn zero() -> Self {
+                $self_ident::splat($gen::zero())
+            }
+        }
+
+        impl<$gen: Copy + num_traits::One> $name {
+            /// Create a new array with every lane set to one.
+            #[must_use]
+            #[inline]
+            pub fn one() -> Self {
+                $self_ident::splat($gen::one())
+            }
+        }
+
+        impl<$gen: Copy + PartialOrd + num_traits::Zero> $name {
+            /// Pick each lane from `neg`, `zero` or `pos` depending on the sign of this lane.
+            ///
+            /// Lanes that are neither less than nor greater than zero, such as NaN, are picked
+            /// from `zero`.
+            #[must_use]
+            #[inline]
+            pub fn select_sign(self, neg: Self, zero: Self, pos: Self) -> Self {
+                let zeroes = $self_ident::splat($gen::zero());
+                let picked = self.packed_gt(zeroes).select(pos, zero);
+                self.packed_lt(zeroes).select(neg, picked)
+            }
+        }
+
+        impl<$gen: PrimInt> $name {
+            /// Reverse the byte order of each lane.
+            #[must_use]
+            #[inline]
+            pub fn swap_bytes(self) -> Self {
+                let lanes = self.into_inner();
+                $self_ident::new([$(lanes[$index].swap_bytes()),*])
+            }
+
+            /// Convert each lane to big endian from the target's endianness.
+            #[must_use]
+            #[inline]
+            pub fn to_be(self) -> Self {
+                let lanes = self.into_inner();
+                $self_ident::new([$(lanes[$index].to_be()),*])
+            }
+
+            /// Convert each lane to little endian from the target's endianness.
+            #[must_use]
+            #[inline]
+            pub fn to_le(self) -> Self {
+                let lanes = self.into_inner();
+                $self_ident::new([$(lanes[$index].to_le()),*])
+            }
+
+            /// Convert each lane to another integer type, clamping it to the target's range.
+            #[must_use]
+            #[inline]
+            pub fn cast_saturating<U: PrimInt>(self) -> $self_ident<U> {
+                let saturate = |lane: $gen| {
+                    U::from(lane).unwrap_or_else(|| {
+                        if lane < $gen::zero() {
+                            U::min_value()
+                        } else {
+                            U::max_value()
+                        }
+                    })
+                };
+
+                let lanes = self.into_inner();
+                $self_ident::new([$(saturate(lanes[$index])),*])
+            }
+        }
+
+        impl<$gen: Copy + CheckedDiv> $name {
+            /// Divide lane by lane, returning `None` if any lane would divide by zero or
+            /// overflow.
+            #[must_use]
+            #[inline]
+            pub fn checked_div(self, other: Self) -> Option<Self> {
+                let (a, b) = (self.into_inner(), other.into_inner());
+                Some($self_ident::new([$(a[$index].checked_div(&b[$index])?),*]))
+            }
+        }
+
+        impl<$gen: Copy + CheckedMul> $name {
+            /// Multiply lane by lane, returning `None` if any lane would overflow.
+            #[must_use]
+            #[inline]
+            pub fn checked_mul(self, other: Self) -> Option<Self> {
+                let (a, b) = (self.into_inner(), other.into_inner());
+                Some($self_ident::new([$(a[$index].checked_mul(&b[$index])?),*]))
+            }
+        }
+
+        #[cfg(feature = "num-integer")]
+        impl<$gen: Copy + num_integer::Integer> $name {
+            /// Get the greatest common divisor of each pair of lanes.
+            #[must_use]
+            #[inline]
+            pub fn gcd(self, other: Self) -> Self {
+                let (a, b) = (self.into_inner(), other.into_inner());
+                $self_ident::new([$(a[$index].gcd(&b[$index])),*])
+            }
+
+            /// Get the least common multiple of each pair of lanes.
+            #[must_use]
+            #[inline]
+            pub fn lcm(self, other: Self) -> Self {
+                let (a, b) = (self.into_inner(), other.into_inner());
+                $self_ident::new([$(a[$index].lcm(&b[$index])),*])
+            }
+        }
+
+        impl<$gen: Copy + WrappingNeg> $name {
+            /// Negate each lane, wrapping around on overflow.
+            ///
+            /// Unlike the `-` operator, this never panics, and can be used on unsigned integers.
+            #[must_use]
+            #[inline]
+            pub fn wrapping_neg(self) -> Self {
+                $self_ident(self.0.wrapping_neg())
+            }
+        }
+
+        impl<$gen: Copy + ops::Shl<u32, Output = $gen>> $name {
+            /// Shift each lane left by the same number of bits.
+            ///
+            /// As with the `<<` operator, `n` must be less than the bit width of `T`.
+            #[must_use]
+            #[inline]
+            pub fn shl_scalar(self, n: u32) -> Self {
+                $self_ident(self.0.shl_scalar(n))
+            }
+
+            /// Shift each lane left by the same number of bits, modulo the bit width of `T`.
+            ///
+            /// Unlike [`shl_scalar`](Self::shl_scalar), any `n` is accepted.
+            #[allow(clippy::cast_possible_truncation)]
+            #[must_use]
+            #[inline]
+            pub fn wrapping_shl(self, n: u32) -> Self {
+                let bits = mem::size_of::<$gen>() as u32 * 8;
+                self.shl_scalar(n % bits)
+            }
+        }
+
+        impl<$gen: Copy + ops::Shr<u32, Output = $gen>> $name {
+            /// Shift each lane right by the same number of bits.
+            ///
+            /// As with the `>>` operator, this is an arithmetic (sign-extending) shift for signed
+            /// integers and a logical shift for unsigned integers. `n` must be less than the bit
+            /// width of `T`.
+            #[must_use]
+            #[inline]
+            pub fn shr_scalar(self, n: u32) -> Self {
+                $self_ident(self.0.shr_scalar(n))
+            }
+
+            /// Shift each lane right by the same number of bits, modulo the bit width of `T`.
+            ///
+            /// Unlike [`shr_scalar`](Self::shr_scalar), any `n` is accepted.
+            #[allow(clippy::cast_possible_truncation)]
+            #[must_use]
+            #[inline]
+            pub fn wrapping_shr(self, n: u32) -> Self {
+                let bits = mem::size_of::<$gen>() as u32 * 8;
+                self.shr_scalar(n % bits)
+            }
+        }
+
+        impl<$gen: Copy + Signed> $name {
+            /// Get the absolute value of each lane.
+            #[must_use]
+            #[inline]
+            pub fn abs(self) -> Self {
+                $self_ident(self.0.abs())
+            }
+        }
+
+        impl<$gen: Copy + Signed + PartialOrd> $name {
+            /// Compare the lanes of two arrays for equality within a tolerance.
+            ///
+            /// A lane is considered equal if the absolute difference between the two values
+            /// is less than or equal to `epsilon`.
+            #[must_use]
+            #[inline]
+            pub fn approx_eq(self, other: Self, epsilon: $gen) -> $mask_ident<$gen> {
+                (self - other).abs().packed_le($self_ident::splat(epsilon))
+            }
+
+            /// Tell if all lanes of two arrays are equal within a tolerance.
+            #[must_use]
+            #[inline]
+            pub fn all_approx_eq(self, other: Self, epsilon: $gen) -> bool {
+                self.approx_eq(other, epsilon).all()
+            }
+        }
+
+        impl<$gen: Copy + PartialEq> $name {
+            /// Compare the lanes of two arrays for equality.
+            #[must_use]
+            #[inline]
+            pub fn packed_eq(self, other: Self) -> $mask_ident<$gen> {
+                $mask_ident(self.0.packed_eq(other.0))
+            }
+
+            /// Compare the lanes of two arrays for inequality.
+            #[must_use]
+            #[inline]
+            pub fn packed_ne(self, other: Self) -> $mask_ident<$gen> {
+                $mask_ident(self.0.packed_ne(other.0))
+            }
+
+            /// Compare the lanes of two arrays for equality, returning integer lanes.
+            ///
+            /// Equal lanes are `-1` (all bits set) and unequal lanes are `0`, which is the same
+            /// representation as the `to_int` method on masks.
+            #[must_use]
+            #[inline]
+            pub fn cmp_eq_mask_int(self, other: Self) -> $self_ident<i32> {
+                self.packed_eq(other).to_int()
+            }
+        }
+
+        impl<$gen: Copy + PartialOrd> $name {
+            /// Compare the lanes of two arrays for less than.
+            #[must_use]
+            #[inline]
+            pub fn packed_lt(self, other: Self) -> $mask_ident<$gen> {
+                $mask_ident(self.0.packed_lt(other.0))
+            }
+
+            /// Compare the lanes of two arrays for less than or equal.
+            #[must_use]
+            #[inline]
+            pub fn packed_le(self, other: Self) -> $mask_ident<$gen> {
+                $mask_ident(self.0.packed_le(other.0))
+            }
+
+            /// Compare the lanes of two arrays for greater than.
+            #[must_use]
+            #[inline]
+            pub fn packed_gt(self, other: Self) -> $mask_ident<$gen> {
+                $mask_ident(self.0.packed_gt(other.0))
+            }
+
+            /// Compare the lanes of two arrays for greater than or equal.
+            #[must_use]
+            #[inline]
+            pub fn packed_ge(self, other: Self) -> $mask_ident<$gen> {
+                $mask_ident(self.0.packed_ge(other.0))
+            }
+        }
+
+        impl<$gen: Copy + PartialOrd> $name {
+            /// Get the minimum of each lane.
+            ///
+            /// If either lane is NaN, which lane is returned depends on the backend. Without
+            /// SIMD, the lane from `other` is returned, which matches `_mm_min_ps` on x86.
+            /// With SIMD, the lane that is not NaN is returned.
+            #[must_use]
+            #[inline]
+            pub fn min(self, other: Self) -> Self {
+                $self_ident(self.0.min(other.0))
+            }
+
+            /// Get the maximum of each lane.
+            ///
+            /// NaN lanes are handled the same way as in [`min`](Self::min).
+            #[must_use]
+            #[inline]
+            pub fn max(self, other: Self) -> Self {
+                $self_ident(self.0.max(other.0))
+            }
+
+            /// Clamp these values to a certain range.
+            #[must_use]
+            #[inline]
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                $self_ident(self.0.clamp(min.0, max.0))
+            }
+
+            /// Clamp these values to a certain range, also returning a mask of the lanes that
+            /// were out of range.
+            ///
+            /// NaN lanes are always reported as out of range.
+            #[must_use]
+            #[inline]
+            pub fn clamp_reporting(self, min: Self, max: Self) -> (Self, $mask_ident<$gen>) {
+                let clamped = self.clamp(min, max);
+                (clamped, self.packed_ne(clamped))
+            }
+
+            /// Clamp every lane to the same range.
+            #[must_use]
+            #[inline]
+            pub fn clamp_scalar(self, min: $gen, max: $gen) -> Self {
+                self.clamp($self_ident::splat(min), $self_ident::splat(max))
+            }
+
+            /// Get the minimum of each lane and a single value.
+            #[must_use]
+            #[inline]
+            pub fn min_scalar(self, value: $gen) -> Self {
+                self.min($self_ident::splat(value))
+            }
+
+            /// Get the maximum of each lane and a single value.
+            #[must_use]
+            #[inline]
+            pub fn max_scalar(self, value: $gen) -> Self {
+                self.max($self_ident::splat(value))
+            }
+
+            /// Get the minimum of each lane, along with a mask of which lanes came from `self`.
+            ///
+            /// Ties are taken from `self`.
+            #[must_use]
+            #[inline]
+            pub fn min_with_mask(self, other: Self) -> (Self, $mask_ident<$gen>) {
+                let mask = self.packed_le(other);
+                (mask.select(self, other), mask)
+            }
+
+            /// Get the maximum of each lane, along with a mask of which lanes came from `self`.
+            ///
+            /// Ties are taken from `self`.
+            #[must_use]
+            #[inline]
+            pub fn max_with_mask(self, other: Self) -> (Self, $mask_ident<$gen>) {
+                let mask = self.packed_ge(other);
+                (mask.select(self, other), mask)
+            }
+
+            /// Get the index of the lane with the minimum value.
+            ///
+            /// If several lanes share the minimum value, the index of the first one is returned.
+            #[must_use]
+            #[inline]
+            pub fn argmin(self) -> usize {
+                let array = self.into_inner();
+                let mut index = 0;
+
+                for (i, lane) in array.iter().enumerate().skip(1) {
+                    if *lane < array[index] {
+                        index = i;
+                    }
+                }
+
+                index
+            }
+
+            /// Get the index of the lane with the maximum value.
+            ///
+            /// If several lanes share the maximum value, the index of the first one is returned.
+            #[must_use]
+            #[inline]
+            pub fn argmax(self) -> usize {
+                let array = self.into_inner();
+                let mut index = 0;
+
+                for (i, lane) in array.iter().enumerate().skip(1) {
+                    if *lane > array[index] {
+                        index = i;
+                    }
+                }
+
+                index
+            }
+
+            /// Get the minimum and maximum lane values in a single pass.
+            ///
+            /// This is equivalent to looking up the lanes at [`argmin`](Self::argmin) and
+            /// [`argmax`](Self::argmax). Since NaN never compares less or greater than another
+            /// value, NaN lanes are skipped unless the first lane is NaN.
+            #[must_use]
+            #[inline]
+            pub fn minmax(self) -> ($gen, $gen) {
+                let array = self.into_inner();
+                let (mut min, mut max) = (array[0], array[0]);
+
+                for lane in array.iter().skip(1) {
+                    if *lane < min {
+                        min = *lane;
+                    }
+                    if *lane > max {
+                        max = *lane;
+                    }
+                }
+
+                (min, max)
+            }
+        }
+
+        impl<$gen: Copy + Real> $name {
+            /// Get the reciprocal of each lane.
+            #[must_use]
+            #[inline]
+            pub fn recip(self) -> Self {
+                $self_ident(self.0.recip())
+            }
+
+            /// Get the floor of each lane.
+            #[must_use]
+            #[inline]
+            pub fn floor(self) -> Self {
+                $self_ident(self.0.floor())
+            }
+
+            /// Get the ceiling of each lane.
+            #[must_use]
+            #[inline]
+            pub fn ceil(self) -> Self {
+                $self_ident(self.0.ceil())
+            }
+
+            /// Round each lane to the nearest integer.
+            #[must_use]
+            #[inline]
+            pub fn round(self) -> Self {
+                $self_ident(self.0.round())
+            }
+
+            /// Round each lane towards zero.
+            #[must_use]
+            #[inline]
+            pub fn trunc(self) -> Self {
+                $self_ident(self.0.trunc())
+            }
+
+            /// Get the square root of each lane.
+            #[must_use]
+            #[inline]
+            pub fn sqrt(self) -> Self {
+                $self_ident(self.0.sqrt())
+            }
+
+            /// Get the approximate reciprocal of the square root of each lane.
+            ///
+            /// Where a dedicated instruction is available (such as `rsqrtps` on x86), this is
+            /// only accurate to roughly 12 bits. Otherwise, it is equivalent to
+            /// `self.sqrt().recip()`. Use [`recip_sqrt_precise`](Self::recip_sqrt_precise)
+            /// when more precision is needed.
+            #[must_use]
+            #[inline]
+            pub fn recip_sqrt(self) -> Self {
+                $self_ident(self.0.recip_sqrt())
+            }
+
+            /// Get the approximate reciprocal of each lane.
+            ///
+            /// Where a dedicated instruction is available (such as `rcpps` on x86), this is
+            /// only accurate to roughly 12 bits. Otherwise, it is equivalent to
+            /// [`recip`](Self::recip). Use [`recip_refined`](Self::recip_refined) to trade
+            /// some of the speed back for precision.
+            #[must_use]
+            #[inline]
+            pub fn recip_fast(self) -> Self {
+                $self_ident(self.0.recip_fast())
+            }
+
+            /// Get the reciprocal of each lane, refined for precision.
+            ///
+            /// This applies `steps` iterations of Newton-Raphson to the result of
+            /// [`recip_fast`](Self::recip_fast). Each iteration roughly doubles the number of
+            /// accurate bits, so two steps are enough for full `f32` precision.
+            ///
+            /// Zero and infinite lanes are not refined, since `x * y` is NaN for them; they give
+            /// the infinity or zero of matching sign.
+            #[must_use]
+            #[inline]
+            pub fn recip_refined(self, steps: u32) -> Self {
+                let two = $self_ident::splat($gen::one() + $gen::one());
+
+                let mut y = self.recip_fast();
+                for _ in 0..steps {
+                    y = y * (two - self * y);
+                }
+                self.is_zero_or_infinite().select(self.recip(), y)
+            }
+
+            /// Get a mask of which lanes are zero or infinite.
+            ///
+            /// Newton-Raphson refinement produces NaN for these lanes.
+            #[inline]
+            fn is_zero_or_infinite(self) -> $mask_ident<$gen> {
+                let infinite = !(self.is_finite() | self.is_nan());
+                self.packed_eq($self_ident::splat($gen::zero())) | infinite
+            }
+
+            /// Clamp each lane to the range `[0, 1]`.
+            #[must_use]
+            #[inline]
+            pub fn clamp01(self) -> Self {
+                self.clamp_scalar($gen::zero(), $gen::one())
+            }
+
+            /// Raise each lane to an integer power.
+            ///
+            /// This uses exponentiation by squaring, taking the reciprocal at the end for
+            /// negative powers. Any lane raised to the power of zero is one, and a zero lane
+            /// raised to a negative power is infinity.
+            #[must_use]
+            #[inline]
+            pub fn powi(self, n: i32) -> Self {
+                // `wrapping_abs` keeps `i32::MIN` as-is, which is 2^31 once reinterpreted.
+                #[allow(clippy::cast_sign_loss)]
+                let mut exponent = n.wrapping_abs() as u32;
+                let mut base = self;
+                let mut result = $self_ident::splat($gen::one());
+
+                while exponent > 0 {
+                    if exponent & 1 == 1 {
+                        result *= base;
+                    }
+                    base = base * base;
+                    exponent >>= 1;
+                }
+
+                if n < 0 {
+                    result.recip()
+                } else {
+                    result
+                }
+            }
+
+            /// Scale this vector down so that its length is at most `max`.
+            ///
+            /// Vectors that are already no longer than `max`, including the zero vector, are
+            /// returned unchanged.
+            #[must_use]
+            #[inline]
+            pub fn clamp_length_max(self, max: $gen) -> Self {
+                // Divide out the largest component first so that squaring can't overflow.
+                let mut largest = $gen::zero();
+                for lane in self.abs_real().into_inner() {
+                    if lane > largest {
+                        largest = lane;
+                    }
+                }
+                if largest == $gen::zero() {
+                    return self;
+                }
+
+                let unit = self / $self_ident::splat(largest);
+                let length = (unit * unit).reduce_sum().sqrt() * largest;
+                let scaled = self * $self_ident::splat(max / length);
+                $mask_ident::splat(length > max).select(scaled, self)
+            }
+
+            /// Get the dot product of this and another array, using fused multiply-add.
+            ///
+            /// Each product after the first is accumulated with a single rounding step, which
+            /// is more accurate than multiplying and then adding the lanes together. This is
+            /// only fast where the platform has an FMA instruction; elsewhere it falls back to
+            /// a slower software implementation.
+            #[must_use]
+            #[inline]
+            pub fn dot_fma(self, other: Self) -> $gen {
+                let (a, b) = (self.into_inner(), other.into_inner());
+                let mut sum = a[0] * b[0];
+                for (&x, &y) in a.iter().zip(b.iter()).skip(1) {
+                    sum = x.mul_add(y, sum);
+                }
+                sum
+            }
+
+            /// Compute `self * splat(factor) + acc` with a single rounding step per lane.
+            ///
+            /// This is the accumulation step of a matrix multiply. Like
+            /// [`dot_fma`](Self::dot_fma), it is only fast where the platform has an FMA
+            /// instruction.
+            #[must_use]
+            #[inline]
+            pub fn mul_add_scalar(self, factor: $gen, acc: Self) -> Self {
+                let (a, c) = (self.into_inner(), acc.into_inner());
+                $self_ident::new([$(a[$index].mul_add(factor, c[$index])),*])
+            }
+
+            /// Round each lane to the nearest integer, rounding halfway cases to even.
+            ///
+            /// Unlike [`round`](Self::round), which rounds halfway cases away from zero, this
+            /// matches the default IEEE 754 rounding mode.
+            #[must_use]
+            #[inline]
+            pub fn round_ties_even(self) -> Self {
+                $self_ident(self.0.round_ties_even())
+            }
+
+            /// Get the absolute value of each lane.
+            ///
+            /// This is equivalent to [`abs`](Self::abs), but only requires `T: Real` instead of
+            /// `T: Signed`, so that it can be used with float types that don't implement
+            /// `Signed`.
+            #[must_use]
+            #[inline]
+            pub fn abs_real(self) -> Self {
+                let lanes = self.into_inner();
+                $self_ident::new([$(Real::abs(lanes[$index])),*])
+            }
+
+            /// Smoothly interpolate between `0` and `1` as each lane goes from `edge0` to `edge1`.
+            ///
+            /// This uses Hermite interpolation, `3t² - 2t³`, where `t` is the lane's position
+            /// between the edges clamped to `[0, 1]`.
+            #[must_use]
+            #[inline]
+            pub fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+                let two = $gen::one() + $gen::one();
+                let three = two + $gen::one();
+
+                let t = ((self - edge0) / (edge1 - edge0)).clamp01();
+                t * t * ($self_ident::splat(three) - $self_ident::splat(two) * t)
+            }
+
+            /// Linearly interpolate between this array and `other`, using a separate factor for
+            /// each lane.
+            ///
+            /// Lanes where `t` is `0` give `self`, and lanes where `t` is `1` give `other`.
+            #[must_use]
+            #[inline]
+            pub fn mix(self, other: Self, t: Self) -> Self {
+                self * ($self_ident::splat($gen::one()) - t) + other * t
+            }
+
+            /// Get `1` for each lane that is at least `edge`, and `0` for the rest.
+            ///
+            /// NaN lanes give `0`.
+            #[must_use]
+            #[inline]
+            pub fn step(self, edge: Self) -> Self {
+                self.packed_ge(edge).select(
+                    $self_ident::splat($gen::one()),
+                    $self_ident::splat($gen::zero()),
+                )
+            }
+
+            /// Get a mask of which lanes are NaN.
+            #[must_use]
+            #[inline]
+            pub fn is_nan(self) -> $mask_ident<$gen> {
+                self.packed_ne(self)
+            }
+
+            /// Get a mask of which lanes are neither infinite nor NaN.
+            #[must_use]
+            #[inline]
+            pub fn is_finite(self) -> $mask_ident<$gen> {
+                // `x - x` is zero for finite values and NaN for infinities and NaN.
+                (self - self).packed_eq($self_ident::splat($gen::zero()))
+            }
+
+            /// Replace every infinite or NaN lane with `fallback`.
+            #[must_use]
+            #[inline]
+            pub fn sanitize(self, fallback: $gen) -> Self {
+                self.is_finite().select(self, $self_ident::splat(fallback))
+            }
+
+            /// Add together every lane, treating NaN lanes as zero.
+            ///
+            /// Infinite lanes are still included in the sum.
+            #[must_use]
+            #[inline]
+            pub fn reduce_sum_nan_as_zero(self) -> $gen {
+                self.is_nan()
+                    .select($self_ident::splat($gen::zero()), self)
+                    .reduce_sum()
+            }
+
+            /// Returns true if any lane is NaN.
+            #[must_use]
+            #[inline]
+            pub fn has_nan(self) -> bool {
+                self.is_nan().any()
+            }
+
+            /// Returns true if every lane is neither infinite nor NaN.
+            #[must_use]
+            #[inline]
+            pub fn is_all_finite(self) -> bool {
+                self.is_finite().all()
+            }
+
+            /// Get the reciprocal of the square root of each lane, refined for precision.
+            ///
+            /// This applies one step of Newton-Raphson iteration to the result of
+            /// [`recip_sqrt`](Self::recip_sqrt), which roughly doubles the number of
+            /// accurate bits.
+            ///
+            /// Zero lanes give infinity and infinite lanes give zero, as they are not refined.
+            #[must_use]
+            #[inline]
+            pub fn recip_sqrt_precise(self) -> Self {
+                let half = $gen::one() / ($gen::one() + $gen::one());
+                let three_halves = $gen::one() + half;
+
+                let y = self.recip_sqrt();
+                let refined =
+                    y * ($self_ident::splat(three_halves) - $self_ident::splat(half) * self * y * y);
+                self.is_zero_or_infinite().select(self.sqrt().recip(), refined)
+            }
+        }
+
+        impl<$gen: Copy> $mask_ident<$gen> {
+            /// Create a new mask from an array.
+            #[must_use]
+            #[inline]
+            pub fn new(array: [bool; $len]) -> Self {
+                $mask_ident(imp::$mask_ident::new(array))
+            }
+
+            /// Create a new mask populated with a single value in all lanes.
+            #[must_use]
+            #[inline]
+            pub fn splat(value: bool) -> Self {
+                $mask_ident(imp::$mask_ident::splat(value))
+            }
+
+            /// Get the underlying array.
+            #[must_use]
+            #[inline]
+            pub fn into_inner(self) -> [bool; $len] {
+                self.0.into_inner()
+            }
+
+            /// Tell if all lanes are true.
+            #[must_use]
+            #[inline]
+            pub fn all(self) -> bool {
+                self.0.all()
+            }
+
+            /// Tell if any lanes are true.
+            #[must_use]
+            #[inline]
+            pub fn any(self) -> bool {
+                self.0.any()
+            }
+
+            /// Get a bitmask where bit `i` is set if lane `i` is true.
+            ///
+            /// The bits above the number of lanes are always zero, so the result is zero
+            /// exactly when [`any`](Self::any) is false.
+            #[must_use]
+            #[inline]
+            pub fn as_u8(self) -> u8 {
+                let mut bits = 0;
+                for i in 0..$len {
+                    bits |= u8::from(self.test(i)) << i;
+                }
+                bits
+            }
+
+            /// Count the lanes that are true.
+            ///
+            /// This is the number of bits set in [`as_u8`](Self::as_u8), and is equal to the
+            /// number of lanes exactly when [`all`](Self::all) is true.
+            #[must_use]
+            #[inline]
+            pub fn count(self) -> u32 {
+                self.as_u8().count_ones()
+            }
+
+            /// Test if a specific lane is true.
+            #[must_use]
+            #[inline]
+            pub fn test(self, index: usize) -> bool {
+                self.0.test(index)
+            }
+
+            /// Set a specific lane to a value.
+            #[inline]
+            pub fn set(&mut self, index: usize, value: bool) {
+                self.0.set(index, value);
+            }
+
+            /// Convert into integer lanes, where true lanes are `-1` and false lanes are `0`.
+            ///
+            /// This matches the all-ones/all-zeroes representation produced by SIMD comparison
+            /// instructions, which is useful for bit-manipulation blending.
+            #[must_use]
+            #[inline]
+            pub fn to_int(self) -> $self_ident<i32> {
+                $self_ident(self.0.to_int())
+            }
+
+            /// Pick each lane from `if_true` where this mask is set, or from `if_false` otherwise.
+            #[must_use]
+            #[inline]
+            pub fn select(self, if_true: $name, if_false: $name) -> $name {
+                $self_ident(self.0.select(if_true.0, if_false.0))
+            }
+
+            /// Combine a set of masks, keeping the lanes that are true in all of them.
+            ///
+            /// Returns a mask with all lanes set if the iterator is empty.
+            #[must_use]
+            #[inline]
+            pub fn fold_and<I: IntoIterator<Item = Self>>(masks: I) -> Self {
+                masks
+                    .into_iter()
+                    .fold($mask_ident::splat(true), ops::BitAnd::bitand)
+            }
+
+            /// Combine a set of masks, keeping the lanes that are true in any of them.
+            ///
+            /// Returns a mask with no lanes set if the iterator is empty.
+            #[must_use]
+            #[inline]
+            pub fn fold_or<I: IntoIterator<Item = Self>>(masks: I) -> Self {
+                masks
+                    .into_iter()
+                    .fold($mask_ident::splat(false), ops::BitOr::bitor)
+            }
+
+            /// Iterate over the lanes of this mask.
+            #[inline]
+            pub fn lanes(self) -> impl Iterator<Item = bool> {
+                (0..$len).map(move |i| self.test(i))
+            }
+
+            /// Get the index of the first lane that is set, or `None` if no lanes are set.
+            #[must_use]
+            #[inline]
+            pub fn first_set(self) -> Option<usize> {
+                match self.as_u8() {
+                    0 => None,
+                    bits => Some(bits.trailing_zeros() as usize),
+                }
+            }
+
+            /// Get the index of the last lane that is set, or `None` if no lanes are set.
+            #[must_use]
+            #[inline]
+            pub fn last_set(self) -> Option<usize> {
+                match self.as_u8() {
+                    0 => None,
+                    bits => Some(7 - bits.leading_zeros() as usize),
+                }
+            }
+
+            /// Take the lanes of this mask that are set, and the lanes of `fallback` elsewhere.
+            ///
+            /// This is the same as `self | fallback`, but reads better when chaining a cascade
+            /// of conditions in order of priority.
+            #[must_use]
+            #[inline]
+            pub fn prefer(self, fallback: Self) -> Self {
+                self | fallback
+            }
+
+            /// Get the lanes that are not true in both masks.
+            #[must_use]
+            #[inline]
+            pub fn nand(self, other: Self) -> Self {
+                !(self & other)
+            }
+
+            /// Get the lanes that are true in neither mask.
+            #[must_use]
+            #[inline]
+            pub fn nor(self, other: Self) -> Self {
+                !(self | other)
+            }
+
+            /// Get the lanes that are the same in both masks.
+            #[must_use]
+            #[inline]
+            pub fn xnor(self, other: Self) -> Self {
+                !(self ^ other)
+            }
+        }
+    };
+}
+
+implementation! {
+    T,
     Double<T>,
     Double,
     DoubleMask,
@@ -668,6 +2130,516 @@ implementation! {
     [0, 1, 2, 3]
 }
 
+impl<T: Copy> Quad<T> {
+    /// Create a new `Quad` by repeating the lanes of a `Double` twice.
+    ///
+    /// The result is `[x, y, x, y]`, which is useful for batching two identical
+    /// 2D operations into a single vector.
+    #[must_use]
+    #[inline]
+    pub fn from_double_broadcast(double: Double<T>) -> Self {
+        Quad(imp::Quad::from_double_broadcast(double.0))
+    }
+
+    /// Create a new `Quad` by repeating a pair of values, as `[a, b, a, b]`.
+    #[must_use]
+    #[inline]
+    pub fn splat_pair(a: T, b: T) -> Self {
+        Quad::from_double_broadcast(Double::new([a, b]))
+    }
+
+    /// Create a new `Quad` by interleaving the lanes of two `Double`s.
+    ///
+    /// The result is `[a.x, b.x, a.y, b.y]`, which turns two 2D points into a single
+    /// vector of their X and Y coordinates. This is the inverse of
+    /// [`deinterleave_doubles`](Self::deinterleave_doubles).
+    #[must_use]
+    #[inline]
+    pub fn interleave_doubles(a: Double<T>, b: Double<T>) -> Self {
+        let (lo, hi) = a.interleave(b);
+        Quad::from_double(lo, hi)
+    }
+
+    /// Split this `Quad` into two `Double`s by taking alternating lanes.
+    ///
+    /// This is the inverse of [`interleave_doubles`](Self::interleave_doubles).
+    #[must_use]
+    #[inline]
+    pub fn deinterleave_doubles(self) -> (Double<T>, Double<T>) {
+        self.lo().deinterleave(self.hi())
+    }
+
+    /// Transpose a 4x4 matrix, given as four row vectors, into its four column vectors.
+    #[must_use]
+    #[inline]
+    pub fn transpose4(rows: [Quad<T>; 4]) -> [Quad<T>; 4] {
+        let [a, b, c, d] = rows;
+        let [a, b, c, d] = imp::Quad::transpose4([a.0, b.0, c.0, d.0]);
+        [Quad(a), Quad(b), Quad(c), Quad(d)]
+    }
+
+    /// Get the lanes in the order `[x, x, y, y]`.
+    #[must_use]
+    #[inline]
+    pub fn xxyy(self) -> Self {
+        Quad(self.0.xxyy())
+    }
+
+    /// Get the lanes in the order `[x, y, x, y]`.
+    #[must_use]
+    #[inline]
+    pub fn xyxy(self) -> Self {
+        Quad(self.0.xyxy())
+    }
+
+    /// Get the lanes in the order `[z, z, w, w]`.
+    #[must_use]
+    #[inline]
+    pub fn zzww(self) -> Self {
+        Quad(self.0.zzww())
+    }
+
+    /// Get the lanes in the order `[x, y, z, w]`, which is the identity swizzle.
+    #[must_use]
+    #[inline]
+    pub fn xyzw(self) -> Self {
+        self
+    }
+}
+
+impl<T: Copy + ops::Add<Output = T> + ops::Mul<Output = T>> Quad<T> {
+    /// Get the dot product of this and another 4D vector.
+    #[must_use]
+    #[inline]
+    pub fn dot4(self, other: Self) -> T {
+        (self * other).reduce_sum()
+    }
+
+    /// Multiply a 4x4 matrix, given as four row vectors, by this vector.
+    ///
+    /// Lane `i` of the result is the dot product of `rows[i]` and this vector.
+    #[must_use]
+    #[inline]
+    pub fn mat4_mul(self, rows: [Quad<T>; 4]) -> Self {
+        // Sum the columns scaled by each lane, which avoids horizontal reductions.
+        let [c0, c1, c2, c3] = Quad::transpose4(rows);
+        let [x, y, z, w] = self.into_inner();
+        c0 * Quad::splat(x) + c1 * Quad::splat(y) + c2 * Quad::splat(z) + c3 * Quad::splat(w)
+    }
+}
+
+impl<T: Real> Quad<T> {
+    /// Get the length of this 4D vector.
+    #[must_use]
+    #[inline]
+    pub fn length4(self) -> T {
+        self.dot4(self).sqrt()
+    }
+}
+
+impl<T: Copy + ops::Add<Output = T> + ops::Mul<Output = T>> Double<T> {
+    /// Multiply this point by a 2x2 matrix.
+    ///
+    /// The matrix is given as `[a, b, c, d]` in row-major order, so the result is
+    /// `[a * x + b * y, c * x + d * y]`.
+    #[must_use]
+    #[inline]
+    pub fn transform_2x2(self, matrix: Quad<T>) -> Self {
+        let products = Quad::from_double_broadcast(self) * matrix;
+        let (x_terms, y_terms) = products.lo().deinterleave(products.hi());
+        x_terms + y_terms
+    }
+}
+
+impl<T: Copy + ops::Add<Output = T> + ops::Mul<Output = T> + ops::Neg<Output = T>> Double<T> {
+    /// Rotate this point around the origin, given the cosine and sine of the angle.
+    ///
+    /// This multiplies the point by the rotation matrix `[[cos, -sin], [sin, cos]]`.
+    #[must_use]
+    #[inline]
+    pub fn rotate(self, cos: T, sin: T) -> Self {
+        self * Double::splat(cos) + self.swap() * Double::new([-sin, sin])
+    }
+}
+
+impl<T: Real> Double<T> {
+    /// Rotate this point around the origin by an angle, in radians.
+    #[must_use]
+    #[inline]
+    pub fn rotate_by_angle(self, angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        self.rotate(cos, sin)
+    }
+}
+
+impl<T: Copy> Double<T> {
+    /// Get the `x` component, which is lane 0.
+    #[must_use]
+    #[inline]
+    pub fn x(self) -> T {
+        self[0]
+    }
+
+    /// Get the `y` component, which is lane 1.
+    #[must_use]
+    #[inline]
+    pub fn y(self) -> T {
+        self[1]
+    }
+
+    /// Set the `x` component, which is lane 0.
+    #[inline]
+    pub fn set_x(&mut self, value: T) {
+        self[0] = value;
+    }
+
+    /// Set the `y` component, which is lane 1.
+    #[inline]
+    pub fn set_y(&mut self, value: T) {
+        self[1] = value;
+    }
+
+    /// Get the lanes as a tuple.
+    #[must_use]
+    #[inline]
+    pub fn into_tuple(self) -> (T, T) {
+        let [x, y] = self.into_inner();
+        (x, y)
+    }
+}
+
+impl<T: Copy> Quad<T> {
+    /// Get the `x` component, which is lane 0.
+    #[must_use]
+    #[inline]
+    pub fn x(self) -> T {
+        self[0]
+    }
+
+    /// Get the `y` component, which is lane 1.
+    #[must_use]
+    #[inline]
+    pub fn y(self) -> T {
+        self[1]
+    }
+
+    /// Get the `z` component, which is lane 2.
+    #[must_use]
+    #[inline]
+    pub fn z(self) -> T {
+        self[2]
+    }
+
+    /// Get the `w` component, which is lane 3.
+    #[must_use]
+    #[inline]
+    pub fn w(self) -> T {
+        self[3]
+    }
+
+    /// Set the `x` component, which is lane 0.
+    #[inline]
+    pub fn set_x(&mut self, value: T) {
+        self[0] = value;
+    }
+
+    /// Set the `y` component, which is lane 1.
+    #[inline]
+    pub fn set_y(&mut self, value: T) {
+        self[1] = value;
+    }
+
+    /// Set the `z` component, which is lane 2.
+    #[inline]
+    pub fn set_z(&mut self, value: T) {
+        self[2] = value;
+    }
+
+    /// Set the `w` component, which is lane 3.
+    #[inline]
+    pub fn set_w(&mut self, value: T) {
+        self[3] = value;
+    }
+
+    /// Get the lanes as a tuple.
+    #[must_use]
+    #[inline]
+    pub fn into_tuple(self) -> (T, T, T, T) {
+        let [x, y, z, w] = self.into_inner();
+        (x, y, z, w)
+    }
+}
+
+impl<T: Copy> DoubleMask<T> {
+    /// Create a new mask from two booleans.
+    ///
+    /// This is equivalent to `DoubleMask::new([a, b])`.
+    #[must_use]
+    #[inline]
+    pub fn from_bools(a: bool, b: bool) -> Self {
+        DoubleMask::new([a, b])
+    }
+
+    /// Convert this mask into a `Double` of ones where lanes are set and zeroes elsewhere.
+    #[must_use]
+    #[inline]
+    pub fn to_double<U: Copy + num_traits::One + num_traits::Zero>(self) -> Double<U> {
+        Double::from_fn(|i| if self.test(i) { U::one() } else { U::zero() })
+    }
+}
+
+impl<T: Copy> QuadMask<T> {
+    /// Create a new mask from four booleans.
+    ///
+    /// This is equivalent to `QuadMask::new([a, b, c, d])`.
+    #[allow(clippy::fn_params_excessive_bools)]
+    #[must_use]
+    #[inline]
+    pub fn from_bools(a: bool, b: bool, c: bool, d: bool) -> Self {
+        QuadMask::new([a, b, c, d])
+    }
+
+    /// Convert this mask into a `Quad` of ones where lanes are set and zeroes elsewhere.
+    #[must_use]
+    #[inline]
+    pub fn to_quad<U: Copy + num_traits::One + num_traits::Zero>(self) -> Quad<U> {
+        Quad::from_fn(|i| if self.test(i) { U::one() } else { U::zero() })
+    }
+}
+
+#[cfg(feature = "half")]
+impl Quad<f32> {
+    /// Convert each lane to a half-precision float, rounding to the nearest value.
+    #[must_use]
+    #[inline]
+    pub fn to_f16(self) -> [half::f16; 4] {
+        let [a, b, c, d] = self.into_inner();
+        [
+            half::f16::from_f32(a),
+            half::f16::from_f32(b),
+            half::f16::from_f32(c),
+            half::f16::from_f32(d),
+        ]
+    }
+
+    /// Create a new `Quad` from four half-precision floats.
+    #[must_use]
+    #[inline]
+    pub fn from_f16(array: [half::f16; 4]) -> Self {
+        let [a, b, c, d] = array;
+        Quad::new([a.to_f32(), b.to_f32(), c.to_f32(), d.to_f32()])
+    }
+}
+
+impl Quad<f32> {
+    /// Raise each lane to the power of the corresponding lane in `e`, approximately.
+    ///
+    /// This computes `exp2(e * log2(self))` with polynomial approximations made of
+    /// vector operations, instead of calling `powf` on each lane. The relative error is
+    /// around `1e-5` for results within the normal range of `f32`, which is usually fine
+    /// for shading, but call `f32::powf` on each lane when accuracy matters.
+    ///
+    /// The lanes of `self` must be positive and finite; the result is unspecified for
+    /// other lanes.
+    #[must_use]
+    #[inline]
+    pub fn powf_fast(self, e: Self) -> Self {
+        (e * self.log2_fast()).exp2_fast()
+    }
+
+    /// Reinterpret the bits of each lane as a `u32`.
+    fn to_bits(self) -> Quad<u32> {
+        // SAFETY: `Quad<f32>` and `Quad<u32>` are transparent wrappers around either arrays or
+        // SIMD vectors of the same size, and every bit pattern is a valid `u32`.
+        unsafe { mem::transmute(self) }
+    }
+
+    /// Reinterpret the bits of each lane as an `f32`.
+    fn from_bits(bits: Quad<u32>) -> Self {
+        // SAFETY: See `to_bits`; every bit pattern is also a valid `f32`.
+        unsafe { mem::transmute(bits) }
+    }
+
+    /// Approximate the base 2 logarithm of each positive, finite lane.
+    fn log2_fast(self) -> Self {
+        // Split each lane into its exponent and a mantissa in `[1, 2)`.
+        // The biased exponent is converted to a float by placing it in the mantissa of 2^23.
+        let bits = self.to_bits();
+        let biased = (bits.shr_scalar(23) & Quad::splat(0xff)) | Quad::splat(0x4b00_0000);
+        let exponent = Quad::from_bits(biased) - Quad::splat(8_388_608.0 + 127.0);
+        let mantissa =
+            Quad::from_bits((bits & Quad::splat(0x007f_ffff)) | Quad::splat(0x3f80_0000));
+
+        // log2(m) = 2 * atanh(t) / ln(2), where t = (m - 1) / (m + 1) is in `[0, 1/3)`.
+        let one = Quad::splat(1.0);
+        let t = (mantissa - one) / (mantissa + one);
+        let t2 = t * t;
+        let series = Quad::splat(1.0 / 9.0);
+        let series = series * t2 + Quad::splat(1.0 / 7.0);
+        let series = series * t2 + Quad::splat(1.0 / 5.0);
+        let series = series * t2 + Quad::splat(1.0 / 3.0);
+        let series = series * t2 + one;
+
+        exponent + series * t * Quad::splat(2.0 * core::f32::consts::LOG2_E)
+    }
+
+    /// Approximate two raised to the power of each lane.
+    fn exp2_fast(self) -> Self {
+        // Split each lane into an integer, applied through the exponent bits, and a
+        // fraction in `[-0.5, 0.5]`.
+        // Adding 1.5 * 2^23 pushes the fraction out of the mantissa, rounding to the nearest
+        // integer, which is then left in the low bits.
+        let magic = Quad::splat(12_582_912.0f32);
+        let clamped = self.clamp_scalar(-126.0, 127.0);
+        let shifted = clamped + magic;
+        let whole = shifted - magic;
+        let fraction = (clamped - whole) * Quad::splat(core::f32::consts::LN_2);
+
+        // Taylor series of `e^x`, which converges quickly for `|x| <= ln(2) / 2`.
+        let series = Quad::splat(1.0 / 720.0);
+        let series = series * fraction + Quad::splat(1.0 / 120.0);
+        let series = series * fraction + Quad::splat(1.0 / 24.0);
+        let series = series * fraction + Quad::splat(1.0 / 6.0);
+        let series = series * fraction + Quad::splat(0.5);
+        let series = series * fraction + Quad::splat(1.0);
+        let series = series * fraction + Quad::splat(1.0);
+
+        // The low bits of `shifted` hold `0x40_0000 + whole`, so this builds `2^whole` from its
+        // biased exponent. `whole` is at least -126, so the subtraction can't underflow.
+        let biased = (shifted.to_bits() + Quad::splat(127)) - Quad::splat(0x4b40_0000);
+        let scale = Quad::from_bits(biased.shl_scalar(23));
+        series * scale
+    }
+}
+
+impl Quad<u32> {
+    /// Count the set bits in each lane.
+    ///
+    /// With the `nightly` feature, this uses the SWAR ("SIMD within a register") algorithm,
+    /// which is made only of shifts, masks and additions, so all four lanes are counted at
+    /// once. Otherwise, each lane is counted separately, which the compiler already
+    /// vectorizes well.
+    #[must_use]
+    #[inline]
+    pub fn count_ones(self) -> Self {
+        #[cfg(feature = "nightly")]
+        {
+            self.count_ones_swar()
+        }
+
+        #[cfg(not(feature = "nightly"))]
+        {
+            Quad::from_fn(|i| self[i].count_ones())
+        }
+    }
+
+    /// Count the set bits in each lane using whole-vector operations.
+    #[cfg(feature = "nightly")]
+    fn count_ones_swar(self) -> Self {
+        let x = self - (self.shr_scalar(1) & Quad::splat(0x5555_5555));
+        let x = (x & Quad::splat(0x3333_3333)) + (x.shr_scalar(2) & Quad::splat(0x3333_3333));
+        let x = (x + x.shr_scalar(4)) & Quad::splat(0x0f0f_0f0f);
+        let x = x + x.shr_scalar(8);
+        let x = x + x.shr_scalar(16);
+        x & Quad::splat(0x3f)
+    }
+}
+
+impl Quad<i32> {
+    /// Count the set bits in each lane.
+    ///
+    /// See [`Quad::<u32>::count_ones`] for details.
+    #[must_use]
+    #[inline]
+    pub fn count_ones(self) -> Quad<u32> {
+        // SAFETY: `Quad<i32>` and `Quad<u32>` are transparent wrappers around either arrays or
+        // SIMD vectors of the same size, and every bit pattern is valid for both.
+        let unsigned: Quad<u32> = unsafe { mem::transmute(self) };
+        unsigned.count_ones()
+    }
+}
+
+macro_rules! float_to_int {
+    ($($name:ident),*) => {
+        $(
+            impl $name<f32> {
+                /// Convert each lane to an `i32`, saturating values that are out of range.
+                ///
+                /// This has the same semantics as an `as` cast: the fractional part is truncated,
+                /// values beyond the range of `i32` are clamped to `i32::MIN` or `i32::MAX`, and
+                /// NaN becomes zero.
+                #[must_use]
+                #[inline]
+                pub fn to_i32_saturating(self) -> $name<i32> {
+                    $name(self.0.to_i32_saturating())
+                }
+            }
+        )*
+    };
+}
+
+float_to_int! { Double, Quad }
+
+macro_rules! widening_from {
+    ($($from:ty => [$($to:ty),*]),*) => {
+        $($(
+            /// Converts each lane to a wider type, which never loses information.
+            impl From<Double<$from>> for Double<$to> {
+                #[inline]
+                fn from(value: Double<$from>) -> Self {
+                    Double::from_fn(|i| <$to>::from(value[i]))
+                }
+            }
+
+            /// Converts each lane to a wider type, which never loses information.
+            impl From<Quad<$from>> for Quad<$to> {
+                #[inline]
+                fn from(value: Quad<$from>) -> Self {
+                    Quad::from_fn(|i| <$to>::from(value[i]))
+                }
+            }
+        )*)*
+    };
+}
+
+widening_from! {
+    u8 => [u16, u32, u64, i16, i32, i64, f32, f64],
+    u16 => [u32, u64, i32, i64, f32, f64],
+    u32 => [u64, i64, f64],
+    i8 => [i16, i32, i64, f32, f64],
+    i16 => [i32, i64, f32, f64],
+    i32 => [i64, f64],
+    f32 => [f64]
+}
+
+macro_rules! hash_bits {
+    ($($name:ident<$ty:ty>),*) => {
+        $(
+            impl $name<$ty> {
+                /// Hash the bit patterns of each lane.
+                ///
+                /// Floating point numbers do not implement `Hash`, so neither does this type.
+                /// This method feeds the raw bits of each lane into the hasher instead, which
+                /// can be used to implement `Hash` for a wrapper type that compares by bit
+                /// pattern.
+                ///
+                /// Note that this is not consistent with `PartialEq`: `0.0` and `-0.0` compare
+                /// equal but hash differently, while NaN lanes hash consistently even though
+                /// they never compare equal.
+                #[inline]
+                pub fn hash_bits<H: Hasher>(&self, state: &mut H) {
+                    let lanes: &[$ty] = self.as_ref();
+                    for lane in lanes {
+                        lane.to_bits().hash(state);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+hash_bits! { Double<f32>, Double<f64>, Quad<f32>, Quad<f64> }
+
 // TODO: Optimize these impls
 
 impl<T: Copy> Double<T> {
@@ -678,6 +2650,13 @@ impl<T: Copy> Double<T> {
         let [a, b] = self.0.into_inner();
         Double::new([b, a])
     }
+
+    /// Extend this point to a `Quad` with the given `z` and `w` components.
+    #[must_use]
+    #[inline]
+    pub fn extend(self, z: T, w: T) -> Quad<T> {
+        Quad::from_double(self, Double::new([z, w]))
+    }
 }
 
 impl<T: Copy> Quad<T> {
@@ -695,6 +2674,15 @@ impl<T: Copy> Quad<T> {
         Double::new([a, b])
     }
 
+    /// Drop the `z` and `w` components, keeping the first two lanes.
+    ///
+    /// This is the inverse of [`Double::extend`], and is equivalent to [`lo`](Self::lo).
+    #[must_use]
+    #[inline]
+    pub fn truncate(self) -> Double<T> {
+        self.lo()
+    }
+
     /// Create a new `Quad` from two `Double`s.
     #[inline]
     pub fn from_double(a: Double<T>, b: Double<T>) -> Self {
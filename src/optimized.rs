@@ -28,12 +28,16 @@ use core::cmp;
 use core::fmt;
 use core::hash::{self, Hash};
 use core::ops;
-use core::simd::{Simd, SimdFloat, SimdInt, SimdOrd, SimdPartialEq, SimdPartialOrd, Mask};
+use core::simd::Which::{First, Second};
+use core::simd::{
+    simd_swizzle, Mask, Simd, SimdElement, SimdFloat, SimdInt, SimdOrd, SimdPartialEq,
+    SimdPartialOrd, SimdUint,
+};
 
 #[cfg(not(feature = "std"))]
 use naive::Foldable;
 use num_traits::real::Real;
-use num_traits::Signed;
+use num_traits::{Signed, WrappingNeg};
 
 #[cfg(feature = "std")]
 use std::simd::StdFloat;
@@ -48,7 +52,7 @@ trait MaybeSimd: Copy + Sized {
     type Double: AsDouble<Self>;
 
     /// The four-wide representation of this type.
-    type Quad: AsQuad<Self>;
+    type Quad: AsQuad<Self> + AsQuadExt<Self>;
 }
 
 impl<T: Copy> MaybeSimd for T {
@@ -56,6 +60,19 @@ impl<T: Copy> MaybeSimd for T {
     default type Quad = naive::Quad<T>;
 }
 
+/// Conversions between the two-wide and four-wide representations of a type.
+trait ConvertWidth: MaybeSimd {
+    fn gen_broadcast_double(double: Self::Double) -> Self::Quad;
+}
+
+impl<T: Copy> ConvertWidth for T {
+    #[inline]
+    default fn gen_broadcast_double(double: Self::Double) -> Self::Quad {
+        let [x, y] = double.gen_into_inner();
+        <Self::Quad as AsQuad<T>>::gen_new([x, y, x, y])
+    }
+}
+
 macro_rules! simd_available {
     (
         $($ty:ty),* $(,)?
@@ -65,6 +82,13 @@ macro_rules! simd_available {
                 type Double = Simd<$ty, 2>;
                 type Quad = Simd<$ty, 4>;
             }
+
+            impl ConvertWidth for $ty {
+                #[inline]
+                fn gen_broadcast_double(double: Simd<$ty, 2>) -> Simd<$ty, 4> {
+                    simd_swizzle!(double, [0, 1, 0, 1])
+                }
+            }
         )*
     }
 }
@@ -203,7 +227,18 @@ macro_rules! implementation {
                 self.to_array()
             }
 
+            fn gen_interleave(self, other: Self) -> (Self, Self) {
+                self.interleave(other)
+            }
+
+            fn gen_deinterleave(self, other: Self) -> (Self, Self) {
+                self.deinterleave(other)
+            }
+
             fn gen_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if f.alternate() {
+                    f.write_str("simd ")?;
+                }
                 fmt::Debug::fmt(self, f)
             }
 
@@ -263,6 +298,25 @@ macro_rules! implementation {
                 )
             }
 
+            fn gen_reduce_sum(self) -> $ty {
+                // Integer sums wrap on overflow.
+                self.reduce_sum()
+            }
+
+            fn gen_reduce_product(self) -> $ty {
+                // Integer products wrap on overflow.
+                self.reduce_product()
+            }
+
+            fn gen_wrapping_neg(self) -> $struct_name<$ty> {
+                // Integer arithmetic on SIMD vectors always wraps.
+                implementation!(
+                    @not_if_float
+                    $is_float,
+                    $struct_name(Self::splat(0) - self)
+                )
+            }
+
             fn gen_shl(self, _other: Self) -> $struct_name<$ty> {
                 implementation!(
                     @not_if_float
@@ -279,6 +333,24 @@ macro_rules! implementation {
                 )
             }
 
+            #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+            fn gen_shl_scalar(self, _n: u32) -> $struct_name<$ty> {
+                implementation!(
+                    @not_if_float
+                    $is_float,
+                    $struct_name(self << Self::splat(_n as $ty))
+                )
+            }
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+            fn gen_shr_scalar(self, _n: u32) -> $struct_name<$ty> {
+                implementation!(
+                    @not_if_float
+                    $is_float,
+                    $struct_name(self >> Self::splat(_n as $ty))
+                )
+            }
+
             fn gen_index(&self, index: usize) -> &$ty {
                 &self.as_array()[index]
             }
@@ -291,6 +363,10 @@ macro_rules! implementation {
                 self == other
             }
 
+            fn gen_select(mask: Self::EqMask, if_true: Self, if_false: Self) -> Self {
+                mask.select(if_true, if_false)
+            }
+
             fn gen_packed_eq(self, other: Self) -> Self::EqMask {
                 self.simd_eq(other)
             }
@@ -391,6 +467,14 @@ macro_rules! implementation {
                 )
             }
 
+            fn gen_trunc(self) -> $struct_name<$ty> {
+                implementation!(
+                    @if_float
+                    $is_float,
+                    call_function: self.trunc => $struct_name
+                )
+            }
+
             fn gen_sqrt(self) -> $struct_name<$ty> {
                 implementation!(
                     @if_float
@@ -402,7 +486,7 @@ macro_rules! implementation {
 
         impl From<naive::$mask_name<$ty>> for Mask<$mask_ty, $len> {
             fn from(other: naive::$mask_name<$ty>) -> Self {
-                Self::from_array(other.into_array())
+                Self::from_array(other.into_inner())
             }
         }
 
@@ -420,6 +504,9 @@ macro_rules! implementation {
             }
 
             fn gen_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if f.alternate() {
+                    f.write_str("simd ")?;
+                }
                 fmt::Debug::fmt(self, f)
             }
 
@@ -466,6 +553,10 @@ macro_rules! implementation {
             fn gen_set(&mut self, index: usize, value: bool) {
                 self.set(index, value);
             }
+
+            fn gen_to_int(self) -> $struct_name<i32> {
+                $struct_name(self.to_int().cast())
+            }
         }
 
         implementation! {
@@ -489,6 +580,7 @@ macro_rules! implementation {
         $assoc_name:ident,
     ) => {
         #[derive(Copy, Clone)]
+        #[repr(transparent)]
         pub(crate) struct $struct_name<$gen: Copy>(<$gen as MaybeSimd>::$assoc_name);
 
         #[derive(Copy, Clone)]
@@ -511,6 +603,8 @@ macro_rules! implementation {
             fn gen_new(array: [$gen; $len]) -> Self;
             fn gen_splat(value: $gen) -> Self;
             fn gen_into_inner(self) -> [$gen; $len];
+            fn gen_interleave(self, other: Self) -> (Self, Self);
+            fn gen_deinterleave(self, other: Self) -> (Self, Self);
             fn gen_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
             where
                 $gen: fmt::Debug;
@@ -548,6 +642,18 @@ macro_rules! implementation {
             where
                 $gen: ops::Neg<Output = $gen>;
 
+            fn gen_reduce_sum(self) -> $gen
+            where
+                $gen: ops::Add<Output = $gen>;
+
+            fn gen_reduce_product(self) -> $gen
+            where
+                $gen: ops::Mul<Output = $gen>;
+
+            fn gen_wrapping_neg(self) -> $struct_name<$gen>
+            where
+                $gen: WrappingNeg;
+
             fn gen_shl(self, other: Self) -> $struct_name<$gen>
             where
                 $gen: ops::Shl<Output = $gen>;
@@ -556,12 +662,21 @@ macro_rules! implementation {
             where
                 $gen: ops::Shr<Output = $gen>;
 
+            fn gen_shl_scalar(self, n: u32) -> $struct_name<$gen>
+            where
+                $gen: ops::Shl<u32, Output = $gen>;
+
+            fn gen_shr_scalar(self, n: u32) -> $struct_name<$gen>
+            where
+                $gen: ops::Shr<u32, Output = $gen>;
+
             fn gen_index(&self, index: usize) -> &$gen;
             fn gen_index_mut(&mut self, index: usize) -> &mut $gen;
 
             fn gen_partial_eq(self, other: Self) -> bool
             where
                 $gen: PartialEq;
+            fn gen_select(mask: Self::EqMask, if_true: Self, if_false: Self) -> Self;
             fn gen_packed_eq(self, other: Self) -> Self::EqMask
             where
                 $gen: PartialEq;
@@ -626,6 +741,10 @@ macro_rules! implementation {
             where
                 $gen: Real;
 
+            fn gen_trunc(self) -> $struct_name<$gen>
+            where
+                $gen: Real;
+
             fn gen_sqrt(self) -> $struct_name<$gen>
             where
                 $gen: Real;
@@ -652,6 +771,7 @@ macro_rules! implementation {
             fn gen_all(self) -> bool;
             fn gen_test(self, index: usize) -> bool;
             fn gen_set(&mut self, index: usize, value: bool);
+            fn gen_to_int(self) -> $struct_name<i32>;
         }
 
         impl<$gen: Copy> $trait_name<$gen> for naive::$struct_name<$gen> {
@@ -672,11 +792,24 @@ macro_rules! implementation {
                 self.into_inner()
             }
 
+            #[inline]
+            fn gen_interleave(self, other: Self) -> (Self, Self) {
+                self.interleave(other)
+            }
+
+            #[inline]
+            fn gen_deinterleave(self, other: Self) -> (Self, Self) {
+                self.deinterleave(other)
+            }
+
             #[inline]
             fn gen_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
             where
                 $gen: fmt::Debug,
             {
+                if f.alternate() {
+                    f.write_str("naive ")?;
+                }
                 fmt::Debug::fmt(self, f)
             }
 
@@ -752,6 +885,30 @@ macro_rules! implementation {
                 $struct_name((-self).into())
             }
 
+            #[inline]
+            fn gen_reduce_sum(self) -> $gen
+            where
+                $gen: ops::Add<Output = $gen>,
+            {
+                self.reduce_sum()
+            }
+
+            #[inline]
+            fn gen_reduce_product(self) -> $gen
+            where
+                $gen: ops::Mul<Output = $gen>,
+            {
+                self.reduce_product()
+            }
+
+            #[inline]
+            fn gen_wrapping_neg(self) -> $struct_name<$gen>
+            where
+                $gen: WrappingNeg,
+            {
+                $struct_name(self.wrapping_neg().into())
+            }
+
             #[inline]
             fn gen_shl(self, other: Self) -> $struct_name<$gen>
             where
@@ -768,6 +925,22 @@ macro_rules! implementation {
                 $struct_name((self >> other).into())
             }
 
+            #[inline]
+            fn gen_shl_scalar(self, n: u32) -> $struct_name<$gen>
+            where
+                $gen: ops::Shl<u32, Output = $gen>,
+            {
+                $struct_name(self.shl_scalar(n).into())
+            }
+
+            #[inline]
+            fn gen_shr_scalar(self, n: u32) -> $struct_name<$gen>
+            where
+                $gen: ops::Shr<u32, Output = $gen>,
+            {
+                $struct_name(self.shr_scalar(n).into())
+            }
+
             #[inline]
             fn gen_index(&self, index: usize) -> &$gen {
                 &self[index]
@@ -786,6 +959,11 @@ macro_rules! implementation {
                 self == other
             }
 
+            #[inline]
+            fn gen_select(mask: Self::EqMask, if_true: Self, if_false: Self) -> Self {
+                mask.select(if_true, if_false)
+            }
+
             #[inline]
             fn gen_packed_eq(self, other: Self) -> Self::EqMask
             where
@@ -930,6 +1108,14 @@ macro_rules! implementation {
                 $struct_name(self.round().into())
             }
 
+            #[inline]
+            fn gen_trunc(self) -> $struct_name<$gen>
+            where
+                $gen: Real,
+            {
+                $struct_name(self.trunc().into())
+            }
+
             #[inline]
             fn gen_sqrt(self) -> $struct_name<$gen>
             where
@@ -942,7 +1128,7 @@ macro_rules! implementation {
         impl<$gen: Copy> $trait_mask_name<$gen> for naive::$mask_name<$gen> {
             #[inline]
             fn gen_new(array: [bool; $len]) -> Self {
-                Self::from_array(array)
+                Self::new(array)
             }
 
             #[inline]
@@ -952,11 +1138,14 @@ macro_rules! implementation {
             
             #[inline]
             fn gen_into_inner(self) -> [bool; $len] {
-                self.into_array()
+                self.into_inner()
             }
 
             #[inline]
             fn gen_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if f.alternate() {
+                    f.write_str("naive ")?;
+                }
                 fmt::Debug::fmt(self, f)
             }
 
@@ -1014,6 +1203,11 @@ macro_rules! implementation {
             fn gen_set(&mut self, index: usize, value: bool) {
                 self.set(index, value);
             }
+
+            #[inline]
+            fn gen_to_int(self) -> $struct_name<i32> {
+                $struct_name::new(self.to_int().into_inner())
+            }
         }
 
         implementation! {
@@ -1046,9 +1240,35 @@ macro_rules! implementation {
                 $struct_name(<$gen as MaybeSimd>::$assoc_name::gen_splat(value))
             }
 
+            /// Read the underlying vector directly, so that SIMD types use an aligned load.
+            ///
+            /// `ptr` must be aligned to `align_of::<Self>()`.
+            #[allow(clippy::cast_ptr_alignment)]
+            pub(crate) unsafe fn load_aligned(ptr: *const $gen) -> Self {
+                $struct_name(core::ptr::read(ptr.cast::<<$gen as MaybeSimd>::$assoc_name>()))
+            }
+
+            /// Write the underlying vector directly, so that SIMD types use an aligned store.
+            ///
+            /// `ptr` must be aligned to `align_of::<Self>()`.
+            #[allow(clippy::cast_ptr_alignment)]
+            pub(crate) unsafe fn store_aligned(self, ptr: *mut $gen) {
+                core::ptr::write(ptr.cast::<<$gen as MaybeSimd>::$assoc_name>(), self.0);
+            }
+
             pub(crate) fn into_inner(self) -> [$gen; $len] {
                 self.0.gen_into_inner()
             }
+
+            pub(crate) fn interleave(self, other: Self) -> (Self, Self) {
+                let (lo, hi) = self.0.gen_interleave(other.0);
+                ($struct_name(lo), $struct_name(hi))
+            }
+
+            pub(crate) fn deinterleave(self, other: Self) -> (Self, Self) {
+                let (even, odd) = self.0.gen_deinterleave(other.0);
+                ($struct_name(even), $struct_name(odd))
+            }
         }
 
         impl<$gen: Copy + PartialEq> $struct_name<$gen> {
@@ -1089,6 +1309,20 @@ macro_rules! implementation {
             pub(crate) fn set(&mut self, index: usize, value: bool) {
                 self.0.gen_set(index, value);
             }
+
+            pub(crate) fn to_int(self) -> $struct_name<i32> {
+                self.0.gen_to_int()
+            }
+
+            pub(crate) fn select(
+                self,
+                if_true: $struct_name<$gen>,
+                if_false: $struct_name<$gen>,
+            ) -> $struct_name<$gen> {
+                $struct_name(<<$gen as MaybeSimd>::$assoc_name as $trait_name<$gen>>::gen_select(
+                    self.0, if_true.0, if_false.0,
+                ))
+            }
         }
 
         impl<$gen: Copy + fmt::Debug> fmt::Debug for $struct_name<$gen> {
@@ -1251,6 +1485,8 @@ macro_rules! implementation {
 
         impl<$gen: Copy + Eq> Eq for $struct_name<$gen> {}
 
+        impl<$gen: Copy> Eq for $mask_name<$gen> {}
+
         impl<$gen: Copy + PartialOrd> PartialOrd for $struct_name<$gen> {
             fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
                 self.0.gen_partial_ord(other.0)
@@ -1269,6 +1505,12 @@ macro_rules! implementation {
             }
         }
 
+        impl<$gen: Copy> hash::Hash for $mask_name<$gen> {
+            fn hash<H: hash::Hasher>(&self, state: &mut H) {
+                self.into_inner().hash(state)
+            }
+        }
+
         impl<$gen: Copy + Default> Default for $struct_name<$gen> {
             fn default() -> Self {
                 $struct_name(<$gen as MaybeSimd>::$assoc_name::gen_default())
@@ -1317,6 +1559,36 @@ macro_rules! implementation {
             }
         }
 
+        impl<$gen: Copy + ops::Add<Output = $gen>> $struct_name<$gen> {
+            pub(crate) fn reduce_sum(self) -> $gen {
+                self.0.gen_reduce_sum()
+            }
+        }
+
+        impl<$gen: Copy + ops::Mul<Output = $gen>> $struct_name<$gen> {
+            pub(crate) fn reduce_product(self) -> $gen {
+                self.0.gen_reduce_product()
+            }
+        }
+
+        impl<$gen: Copy + WrappingNeg> $struct_name<$gen> {
+            pub(crate) fn wrapping_neg(self) -> Self {
+                self.0.gen_wrapping_neg()
+            }
+        }
+
+        impl<$gen: Copy + ops::Shl<u32, Output = $gen>> $struct_name<$gen> {
+            pub(crate) fn shl_scalar(self, n: u32) -> Self {
+                self.0.gen_shl_scalar(n)
+            }
+        }
+
+        impl<$gen: Copy + ops::Shr<u32, Output = $gen>> $struct_name<$gen> {
+            pub(crate) fn shr_scalar(self, n: u32) -> Self {
+                self.0.gen_shr_scalar(n)
+            }
+        }
+
         impl<$gen: Copy + PartialOrd> $struct_name<$gen> {
             pub(crate) fn max(self, other: Self) -> Self {
                 self.0.gen_max(other.0)
@@ -1357,20 +1629,193 @@ macro_rules! implementation {
             }
 
             pub(crate) fn floor(self) -> Self {
-                self.0.gen_floor()
+                Rounding::gen_floor(self)
             }
 
             pub(crate) fn ceil(self) -> Self {
-                self.0.gen_ceil()
+                Rounding::gen_ceil(self)
             }
 
             pub(crate) fn round(self) -> Self {
                 self.0.gen_round()
             }
+
+            pub(crate) fn trunc(self) -> Self {
+                Rounding::gen_trunc(self)
+            }
+
+            pub(crate) fn round_ties_even(self) -> Self {
+                Rounding::gen_round_ties_even(self)
+            }
+
+            pub(crate) fn recip_sqrt(self) -> Self {
+                RecipSqrt::gen_recip_sqrt(self)
+            }
+
+            pub(crate) fn recip_fast(self) -> Self {
+                RecipFast::gen_recip_fast(self)
+            }
+        }
+
+        impl<$gen: Real> RecipSqrt for $struct_name<$gen> {
+            #[inline]
+            default fn gen_recip_sqrt(self) -> Self {
+                self.sqrt().recip()
+            }
+        }
+
+        impl<$gen: Real> RecipFast for $struct_name<$gen> {
+            #[inline]
+            default fn gen_recip_fast(self) -> Self {
+                self.recip()
+            }
+        }
+
+        impl<$gen: Real> Rounding for $struct_name<$gen> {
+            #[inline]
+            default fn gen_floor(self) -> Self {
+                self.0.gen_floor()
+            }
+
+            #[inline]
+            default fn gen_ceil(self) -> Self {
+                self.0.gen_ceil()
+            }
+
+            #[inline]
+            default fn gen_trunc(self) -> Self {
+                self.0.gen_trunc()
+            }
+
+            #[inline]
+            default fn gen_round_ties_even(self) -> Self {
+                let mut lanes = self.into_inner();
+                for lane in lanes.iter_mut() {
+                    *lane = naive::round_ties_even(*lane);
+                }
+                Self::new(lanes)
+            }
         }
     };
 }
 
+/// Reciprocal square roots, which have a dedicated approximate instruction on some platforms.
+trait RecipSqrt: Sized {
+    fn gen_recip_sqrt(self) -> Self;
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse"
+))]
+impl RecipSqrt for Quad<f32> {
+    #[inline]
+    fn gen_recip_sqrt(self) -> Self {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm_rsqrt_ps;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm_rsqrt_ps;
+
+        // SAFETY: The "sse" target feature is enabled.
+        let approx = unsafe { _mm_rsqrt_ps(self.0.into()) };
+        Quad(approx.into())
+    }
+}
+
+/// Reciprocals, which have a dedicated approximate instruction on some platforms.
+trait RecipFast: Sized {
+    fn gen_recip_fast(self) -> Self;
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse"
+))]
+impl RecipFast for Quad<f32> {
+    #[inline]
+    fn gen_recip_fast(self) -> Self {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm_rcp_ps;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm_rcp_ps;
+
+        // SAFETY: The "sse" target feature is enabled.
+        let approx = unsafe { _mm_rcp_ps(self.0.into()) };
+        Quad(approx.into())
+    }
+}
+
+/// Rounding functions, which have dedicated instructions on some platforms.
+///
+/// Without `std`, the portable SIMD rounding functions are unavailable and the default
+/// implementations fall back to calling `libm` on each lane.
+trait Rounding: Sized {
+    fn gen_floor(self) -> Self;
+    fn gen_ceil(self) -> Self;
+    fn gen_trunc(self) -> Self;
+    fn gen_round_ties_even(self) -> Self;
+}
+
+// `_mm_round_ps` only supports IEEE rounding modes, so it can't be used for `round`, which
+// rounds halfway cases away from zero. It can be used for `round_ties_even`, which is the
+// default IEEE mode.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse4.1"
+))]
+impl Rounding for Quad<f32> {
+    #[inline]
+    fn gen_floor(self) -> Self {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm_floor_ps;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm_floor_ps;
+
+        // SAFETY: The "sse4.1" target feature is enabled.
+        let floor = unsafe { _mm_floor_ps(self.0.into()) };
+        Quad(floor.into())
+    }
+
+    #[inline]
+    fn gen_ceil(self) -> Self {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm_ceil_ps;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm_ceil_ps;
+
+        // SAFETY: The "sse4.1" target feature is enabled.
+        let ceil = unsafe { _mm_ceil_ps(self.0.into()) };
+        Quad(ceil.into())
+    }
+
+    #[inline]
+    fn gen_trunc(self) -> Self {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::{_mm_round_ps, _MM_FROUND_NO_EXC, _MM_FROUND_TO_ZERO};
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::{_mm_round_ps, _MM_FROUND_NO_EXC, _MM_FROUND_TO_ZERO};
+
+        // SAFETY: The "sse4.1" target feature is enabled.
+        let trunc =
+            unsafe { _mm_round_ps::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(self.0.into()) };
+        Quad(trunc.into())
+    }
+
+    #[inline]
+    fn gen_round_ties_even(self) -> Self {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::{_mm_round_ps, _MM_FROUND_NO_EXC, _MM_FROUND_TO_NEAREST_INT};
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::{_mm_round_ps, _MM_FROUND_NO_EXC, _MM_FROUND_TO_NEAREST_INT};
+
+        // SAFETY: The "sse4.1" target feature is enabled.
+        let rounded = unsafe {
+            _mm_round_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(self.0.into())
+        };
+        Quad(rounded.into())
+    }
+}
+
 implementation! {
     T, 2,
     Double, DoubleMask, AsDouble, AsDoubleMask, Double,
@@ -1380,3 +1825,104 @@ implementation! {
     T, 4,
     Quad, QuadMask, AsQuad, AsQuadMask, Quad,
 }
+
+macro_rules! float_to_int {
+    ($($name:ident),*) => {
+        $(
+            impl $name<f32> {
+                pub(crate) fn to_i32_saturating(self) -> $name<i32> {
+                    // `Simd::cast` follows the same saturating rules as `as`.
+                    $name(self.0.cast())
+                }
+            }
+        )*
+    };
+}
+
+float_to_int! { Double, Quad }
+
+/// Operations that only make sense for four-wide vectors.
+trait AsQuadExt<T: Copy>: Sized {
+    fn gen_transpose4(rows: [Self; 4]) -> [Self; 4];
+    fn gen_xxyy(self) -> Self;
+    fn gen_xyxy(self) -> Self;
+    fn gen_zzww(self) -> Self;
+}
+
+impl<T: Copy> AsQuadExt<T> for naive::Quad<T> {
+    #[inline]
+    fn gen_transpose4(rows: [Self; 4]) -> [Self; 4] {
+        naive::Quad::transpose4(rows)
+    }
+
+    #[inline]
+    fn gen_xxyy(self) -> Self {
+        self.xxyy()
+    }
+
+    #[inline]
+    fn gen_xyxy(self) -> Self {
+        self.xyxy()
+    }
+
+    #[inline]
+    fn gen_zzww(self) -> Self {
+        self.zzww()
+    }
+}
+
+impl<T: SimdElement> AsQuadExt<T> for Simd<T, 4> {
+    #[inline]
+    fn gen_transpose4(rows: [Self; 4]) -> [Self; 4] {
+        // This follows the same unpack/move pattern as `_MM_TRANSPOSE4_PS`.
+        let [r0, r1, r2, r3] = rows;
+        let (t0, t1) = r0.interleave(r1);
+        let (t2, t3) = r2.interleave(r3);
+
+        [
+            simd_swizzle!(t0, t2, [First(0), First(1), Second(0), Second(1)]),
+            simd_swizzle!(t0, t2, [First(2), First(3), Second(2), Second(3)]),
+            simd_swizzle!(t1, t3, [First(0), First(1), Second(0), Second(1)]),
+            simd_swizzle!(t1, t3, [First(2), First(3), Second(2), Second(3)]),
+        ]
+    }
+
+    #[inline]
+    fn gen_xxyy(self) -> Self {
+        simd_swizzle!(self, [0, 0, 1, 1])
+    }
+
+    #[inline]
+    fn gen_xyxy(self) -> Self {
+        simd_swizzle!(self, [0, 1, 0, 1])
+    }
+
+    #[inline]
+    fn gen_zzww(self) -> Self {
+        simd_swizzle!(self, [2, 2, 3, 3])
+    }
+}
+
+impl<T: Copy> Quad<T> {
+    pub(crate) fn from_double_broadcast(double: Double<T>) -> Self {
+        Quad(T::gen_broadcast_double(double.0))
+    }
+
+    pub(crate) fn transpose4(rows: [Self; 4]) -> [Self; 4] {
+        let [a, b, c, d] = rows;
+        let [a, b, c, d] = AsQuadExt::gen_transpose4([a.0, b.0, c.0, d.0]);
+        [Quad(a), Quad(b), Quad(c), Quad(d)]
+    }
+
+    pub(crate) fn xxyy(self) -> Self {
+        Quad(self.0.gen_xxyy())
+    }
+
+    pub(crate) fn xyxy(self) -> Self {
+        Quad(self.0.gen_xyxy())
+    }
+
+    pub(crate) fn zzww(self) -> Self {
+        Quad(self.0.gen_zzww())
+    }
+}
@@ -32,14 +32,13 @@
 #![allow(clippy::many_single_char_names)]
 
 use core::cmp;
-use core::convert::TryInto;
 use core::fmt;
 use core::hash;
 use core::marker::PhantomData;
 use core::ops;
 
 use num_traits::real::Real;
-use num_traits::Signed;
+use num_traits::{Signed, WrappingNeg};
 
 /// A set of two values.
 #[derive(Copy, Clone)]
@@ -174,14 +173,7 @@ macro_rules! implementation {
 
         impl<$gen: Copy> PartialOrd for $mask_ident<$gen> {
             fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-                $(
-                    match self.mask[$index].partial_cmp(&other.mask[$index]) {
-                        Some(cmp::Ordering::Equal) => (),
-                        non_eq => return non_eq,
-                    }
-                )*
-
-                Some(cmp::Ordering::Equal)
+                Some(self.cmp(other))
             }
         }
 
@@ -412,12 +404,18 @@ macro_rules! implementation {
                 $self_ident(array)
             }
 
-            /// Create a new array by copying it from a slice.
+            /// Read an array from a pointer aligned to `T`.
+            #[cfg_attr(feature = "nightly", allow(dead_code))]
+            #[inline]
+            pub(crate) unsafe fn load_aligned(ptr: *const $gen) -> Self {
+                $self_ident(core::ptr::read(ptr.cast::<[$gen; $len]>()))
+            }
+
+            /// Write this array to a pointer aligned to `T`.
+            #[cfg_attr(feature = "nightly", allow(dead_code))]
             #[inline]
-            pub(crate) fn from_slice(slice: &[$gen]) -> Self {
-                let array_ref: &[$gen; $len] =
-                    &slice[..$len].try_into().expect("slice length is not equal to array length");
-                $self_ident(*array_ref)
+            pub(crate) unsafe fn store_aligned(self, ptr: *mut $gen) {
+                core::ptr::write(ptr.cast::<[$gen; $len]>(), self.0);
             }
 
             /// Get the underlying array.
@@ -425,6 +423,36 @@ macro_rules! implementation {
                 self.0
             }
 
+            /// Interleave the lanes of this array with another, returning the low and high halves.
+            pub(crate) fn interleave(self, other: Self) -> (Self, Self) {
+                let (a, b) = (self.0, other.0);
+                let lo = [$({
+                    let i = $index;
+                    if i % 2 == 0 { a[i / 2] } else { b[i / 2] }
+                }),*];
+                let hi = [$({
+                    let i = $index + $len;
+                    if i % 2 == 0 { a[i / 2] } else { b[i / 2] }
+                }),*];
+
+                ($self_ident(lo), $self_ident(hi))
+            }
+
+            /// Split the lanes of this array and another into the even and odd lanes.
+            pub(crate) fn deinterleave(self, other: Self) -> (Self, Self) {
+                let (a, b) = (self.0, other.0);
+                let even = [$({
+                    let i = 2 * $index;
+                    if i < $len { a[i] } else { b[i - $len] }
+                }),*];
+                let odd = [$({
+                    let i = 2 * $index + 1;
+                    if i < $len { a[i] } else { b[i - $len] }
+                }),*];
+
+                ($self_ident(even), $self_ident(odd))
+            }
+
             /// Create a new vector with one element repeated.
             pub(crate) fn splat(value: $gen) -> Self
             where
@@ -440,7 +468,7 @@ macro_rules! implementation {
         impl<$gen: Copy> $mask_ident<$gen> {
             /// Create a new array from a set of booleans.
             #[inline]
-            pub(crate) fn from_array(array: [bool; $len]) -> Self {
+            pub(crate) fn new(array: [bool; $len]) -> Self {
                 array.into()
             }
 
@@ -455,7 +483,7 @@ macro_rules! implementation {
 
             /// Convert into a set of booleans.
             #[inline]
-            pub(crate) fn into_array(self) -> [bool; $len] {
+            pub(crate) fn into_inner(self) -> [bool; $len] {
                 self.mask
             }
 
@@ -482,6 +510,63 @@ macro_rules! implementation {
             pub(crate) fn any(&self) -> bool {
                 $(self.mask[$index] ||)* false
             }
+
+            /// Convert into an array of integers, where `-1` is true and `0` is false.
+            #[inline]
+            pub(crate) fn to_int(self) -> $self_ident<i32> {
+                $self_ident(self.mask.fold(|a| -i32::from(a)))
+            }
+
+            /// Pick each element from `if_true` where this mask is set, or `if_false` otherwise.
+            #[inline]
+            pub(crate) fn select(self, if_true: $name, if_false: $name) -> $name {
+                $self_ident([$(
+                    if self.mask[$index] { if_true.0[$index] } else { if_false.0[$index] }
+                ),*])
+            }
+        }
+
+        impl<$gen: Copy + ops::Add<Output = $gen>> $name {
+            /// Add together every element of this array, from left to right.
+            pub(crate) fn reduce_sum(self) -> $gen {
+                let mut sum = self.0[0];
+                for &lane in &self.0[1..] {
+                    sum = sum + lane;
+                }
+                sum
+            }
+        }
+
+        impl<$gen: Copy + ops::Mul<Output = $gen>> $name {
+            /// Multiply together every element of this array, from left to right.
+            pub(crate) fn reduce_product(self) -> $gen {
+                let mut product = self.0[0];
+                for &lane in &self.0[1..] {
+                    product = product * lane;
+                }
+                product
+            }
+        }
+
+        impl<$gen: Copy + WrappingNeg> $name {
+            /// Negate every element of this array, wrapping around on overflow.
+            pub(crate) fn wrapping_neg(self) -> Self {
+                $self_ident(self.0.fold(|a| a.wrapping_neg()))
+            }
+        }
+
+        impl<$gen: Copy + ops::Shl<u32, Output = $gen>> $name {
+            /// Shift every element of this array left by the same amount.
+            pub(crate) fn shl_scalar(self, n: u32) -> Self {
+                $self_ident(self.0.fold(|a| a << n))
+            }
+        }
+
+        impl<$gen: Copy + ops::Shr<u32, Output = $gen>> $name {
+            /// Shift every element of this array right by the same amount.
+            pub(crate) fn shr_scalar(self, n: u32) -> Self {
+                $self_ident(self.0.fold(|a| a >> n))
+            }
         }
 
         impl<$gen: Copy + Signed> $name {
@@ -565,6 +650,27 @@ macro_rules! implementation {
             pub(crate) fn round(self) -> Self {
                 $self_ident(self.0.fold(|a| a.round()))
             }
+
+            /// Truncate the fractional part of this array.
+            pub(crate) fn trunc(self) -> Self {
+                $self_ident(self.0.fold(|a| a.trunc()))
+            }
+
+            /// Round this array to the nearest integer, rounding halfway cases to even.
+            #[cfg_attr(feature = "nightly", allow(dead_code))]
+            pub(crate) fn round_ties_even(self) -> Self {
+                $self_ident(self.0.fold(round_ties_even))
+            }
+
+            /// Find the approximate reciprocal of this array.
+            pub(crate) fn recip_fast(self) -> Self {
+                self.recip()
+            }
+
+            /// Find the reciprocal square root of this array.
+            pub(crate) fn recip_sqrt(self) -> Self {
+                $self_ident(self.0.fold(|a| a.sqrt().recip()))
+            }
         }
     }
 }
@@ -587,38 +693,78 @@ implementation! {
     [0, 1, 2, 3]
 }
 
-impl<T: Copy> Double<T> {
-    /// Swap the elements of this array.
-    pub(crate) fn yx(self) -> Self {
-        let Self([a, b]) = self;
-        Self([b, a])
+impl<T: Copy> Quad<T> {
+    /// Create a new `Quad` by repeating a `Double` twice.
+    #[cfg_attr(feature = "nightly", allow(dead_code))]
+    pub(crate) fn from_double_broadcast(x: Double<T>) -> Self {
+        let Double([a, b]) = x;
+        Self([a, b, a, b])
     }
-}
 
-impl<T: Copy> Quad<T> {
-    /// Split this `Quad` into two `Double`s.
-    pub(crate) fn split(self) -> (Double<T>, Double<T>) {
-        let Self([a, b, c, d]) = self;
-        (Double([a, b]), Double([c, d]))
+    /// Transpose four rows of a 4x4 matrix into four columns.
+    pub(crate) fn transpose4(rows: [Self; 4]) -> [Self; 4] {
+        let [Self([a0, a1, a2, a3]), Self([b0, b1, b2, b3]), Self([c0, c1, c2, c3]), Self([d0, d1, d2, d3])] =
+            rows;
+
+        [
+            Self([a0, b0, c0, d0]),
+            Self([a1, b1, c1, d1]),
+            Self([a2, b2, c2, d2]),
+            Self([a3, b3, c3, d3]),
+        ]
     }
 
-    /// Get the first two elements of this array as a double.
-    pub(crate) fn xy(self) -> Double<T> {
+    /// Duplicate the first two elements, as `[x, x, y, y]`.
+    pub(crate) fn xxyy(self) -> Self {
         let Self([a, b, _, _]) = self;
-        Double([a, b])
+        Self([a, a, b, b])
     }
 
-    /// Get the last two elements of this array as a double.
-    pub(crate) fn zw(self) -> Double<T> {
+    /// Repeat the first two elements, as `[x, y, x, y]`.
+    pub(crate) fn xyxy(self) -> Self {
+        let Self([a, b, _, _]) = self;
+        Self([a, b, a, b])
+    }
+
+    /// Duplicate the last two elements, as `[z, z, w, w]`.
+    pub(crate) fn zzww(self) -> Self {
         let Self([_, _, c, d]) = self;
-        Double([c, d])
+        Self([c, c, d, d])
     }
+}
 
-    /// Create a new `Quad` from two `Double`s.
-    pub(crate) fn from_doubles(x: Double<T>, y: Double<T>) -> Self {
-        let Double([a, b]) = x;
-        let Double([c, d]) = y;
-        Self([a, b, c, d])
+macro_rules! float_to_int {
+    ($($name:ident),*) => {
+        $(
+            impl $name<f32> {
+                /// Convert each element to an `i32`, saturating out-of-range values and mapping NaN to zero.
+                pub(crate) fn to_i32_saturating(self) -> $name<i32> {
+                    $name(self.0.fold(saturate_i32))
+                }
+            }
+        )*
+    };
+}
+
+float_to_int! { Double, Quad }
+
+/// Convert an `f32` to an `i32`, truncating, saturating out-of-range values and mapping NaN to
+/// zero.
+///
+/// Before Rust 1.45, an out-of-range `as` cast was undefined behavior, so the saturation is
+/// spelled out here.
+#[allow(clippy::cast_possible_truncation)]
+#[inline]
+fn saturate_i32(value: f32) -> i32 {
+    // -2^31 and 2^31 are exactly representable, so these compare without rounding.
+    if value.is_nan() {
+        0
+    } else if value <= -2_147_483_648.0 {
+        i32::MIN
+    } else if value >= 2_147_483_648.0 {
+        i32::MAX
+    } else {
+        value as i32
     }
 }
 
@@ -641,3 +787,17 @@ pub(crate) fn max<T: PartialOrd>(a: T, b: T) -> T {
         b
     }
 }
+
+/// Round to the nearest integer, rounding halfway cases to even.
+#[inline]
+pub(crate) fn round_ties_even<T: Real>(lane: T) -> T {
+    let two = T::one() + T::one();
+    let rounded = lane.round();
+
+    if (rounded - lane).abs() == T::one() / two {
+        // Halving is exact, so this picks whichever neighbor is even.
+        (lane / two).round() * two
+    } else {
+        rounded
+    }
+}
@@ -24,7 +24,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use breadsimd::{Double, Quad};
+#![cfg_attr(feature = "nightly", feature(portable_simd))]
+
+use breadsimd::{where_true, windows2, Double, DoubleMask, Quad, QuadMask, SimdTuple};
 
 fn ints_to_floats(a: [u32; 4]) -> [f32; 4] {
     [a[0] as f32, a[1] as f32, a[2] as f32, a[3] as f32]
@@ -67,6 +69,10 @@ macro_rules! run_test {
         run_test::<u128>($input1, $input2, $with_double, $with_quad, $output);
         run_test::<u32>($input1, $input2, $with_double, $with_quad, $output);
         run_test::<i32>($input1, $input2, $with_double, $with_quad, $output);
+        run_test::<u16>($input1, $input2, $with_double, $with_quad, $output);
+        run_test::<i16>($input1, $input2, $with_double, $with_quad, $output);
+        run_test::<u8>($input1, $input2, $with_double, $with_quad, $output);
+        run_test::<i8>($input1, $input2, $with_double, $with_quad, $output);
         run_test::<f32>(
             ints_to_floats($input1),
             ints_to_floats($input2),
@@ -86,6 +92,10 @@ macro_rules! run_test {
         run_test::<u128>($input1, $input2, $with_double, $with_quad, $output);
         run_test::<u32>($input1, $input2, $with_double, $with_quad, $output);
         run_test::<i32>($input1, $input2, $with_double, $with_quad, $output);
+        run_test::<u16>($input1, $input2, $with_double, $with_quad, $output);
+        run_test::<i16>($input1, $input2, $with_double, $with_quad, $output);
+        run_test::<u8>($input1, $input2, $with_double, $with_quad, $output);
+        run_test::<i8>($input1, $input2, $with_double, $with_quad, $output);
     }};
 }
 
@@ -310,3 +320,1509 @@ fn ord() {
         [1, 3, 3, 5]
     );
 }
+
+#[test]
+fn argmin_argmax() {
+    assert_eq!(Quad::new([3, 1, 4, 1]).argmin(), 1);
+    assert_eq!(Quad::new([3, 1, 4, 4]).argmax(), 2);
+    assert_eq!(Double::new([2.0f32, 1.0]).argmin(), 1);
+    assert_eq!(Double::new([2.0f32, 2.0]).argmax(), 0);
+}
+
+#[test]
+fn transpose4() {
+    let rows = [
+        Quad::new([1, 2, 3, 4]),
+        Quad::new([5, 6, 7, 8]),
+        Quad::new([9, 10, 11, 12]),
+        Quad::new([13, 14, 15, 16]),
+    ];
+
+    let columns = Quad::transpose4(rows);
+    assert_eq!(columns[0], Quad::new([1, 5, 9, 13]));
+    assert_eq!(columns[3], Quad::new([4, 8, 12, 16]));
+    assert_eq!(Quad::transpose4(columns), rows);
+}
+
+#[test]
+fn interleave() {
+    let a = Quad::new([1, 2, 3, 4]);
+    let b = Quad::new([5, 6, 7, 8]);
+
+    let (lo, hi) = a.interleave(b);
+    assert_eq!(lo, Quad::new([1, 5, 2, 6]));
+    assert_eq!(hi, Quad::new([3, 7, 4, 8]));
+    assert_eq!(lo.deinterleave(hi), (a, b));
+
+    let (lo, hi) = Double::new([1.0f32, 2.0]).interleave(Double::new([3.0, 4.0]));
+    assert_eq!(lo, Double::new([1.0, 3.0]));
+    assert_eq!(hi, Double::new([2.0, 4.0]));
+    assert_eq!(
+        lo.deinterleave(hi),
+        (Double::new([1.0, 2.0]), Double::new([3.0, 4.0]))
+    );
+}
+
+#[test]
+fn to_i32_saturating() {
+    assert_eq!(
+        Quad::new([1e30f32, -1e30, f32::NAN, 2.5]).to_i32_saturating(),
+        Quad::new([i32::MAX, i32::MIN, 0, 2])
+    );
+    assert_eq!(
+        Double::new([-2.5f32, f32::INFINITY]).to_i32_saturating(),
+        Double::new([-2, i32::MAX])
+    );
+}
+
+#[test]
+fn generic_over_width() {
+    fn centroid<V>(points: &[V]) -> V
+    where
+        V: SimdTuple + core::ops::Add<Output = V> + core::ops::Div<Output = V>,
+        V::Lane: From<u8>,
+    {
+        let sum = points
+            .iter()
+            .fold(V::splat(V::Lane::from(0)), |sum, point| sum + *point);
+        sum / V::splat(V::Lane::from(points.len() as u8))
+    }
+
+    let points = [
+        Double::new([0, 0]),
+        Double::new([4, 2]),
+        Double::new([2, 4]),
+    ];
+    assert_eq!(centroid(&points), Double::new([2, 2]));
+
+    let points = [Quad::<f32>::splat(1.0), Quad::new([3.0, 5.0, 7.0, 9.0])];
+    assert_eq!(centroid(&points), Quad::new([2.0, 3.0, 4.0, 5.0]));
+    assert_eq!(
+        SimdTuple::into_inner(centroid(&points)),
+        [2.0, 3.0, 4.0, 5.0]
+    );
+}
+
+#[test]
+fn from_double_broadcast() {
+    assert_eq!(
+        Quad::from_double_broadcast(Double::new([1, 2])),
+        Quad::new([1, 2, 1, 2])
+    );
+    assert_eq!(
+        Quad::from_double_broadcast(Double::new([1.5f32, -2.0])),
+        Quad::new([1.5, -2.0, 1.5, -2.0])
+    );
+}
+
+#[test]
+fn hash_bits() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+
+    fn hash_of(quad: Quad<f32>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        quad.hash_bits(&mut hasher);
+        hasher.finish()
+    }
+
+    let a = Quad::new([1.0, 2.5, f32::NAN, -0.0]);
+    let b = Quad::new([1.0, 2.5, f32::NAN, -0.0]);
+    assert_eq!(hash_of(a), hash_of(b));
+    assert_ne!(hash_of(a), hash_of(Quad::new([1.0, 2.5, f32::NAN, 0.0])));
+}
+
+#[test]
+fn approx_eq() {
+    assert!(Double::new([1.0, 2.0]).all_approx_eq(Double::new([1.0001, 2.0]), 0.001));
+    assert!(!Double::new([1.0, 2.0]).all_approx_eq(Double::new([1.1, 2.0]), 0.001));
+
+    let mask = Quad::new([1.0f32, 2.0, 3.0, 4.0]).approx_eq(Quad::new([1.0, 2.5, 3.05, 3.0]), 0.1);
+    assert_eq!(mask.into_inner(), [true, false, true, false]);
+}
+
+#[test]
+fn recip_sqrt() {
+    let input = [1.0f32, 2.0, 16.0, 100.0];
+    let quad = Quad::new(input);
+
+    let approx = quad.recip_sqrt().into_inner();
+    let precise = quad.recip_sqrt_precise().into_inner();
+    for i in 0..4 {
+        let expected = 1.0 / input[i].sqrt();
+        assert!((approx[i] - expected).abs() <= expected * 1e-3);
+        assert!((precise[i] - expected).abs() <= expected * 1e-6);
+    }
+
+    let precise = Double::new([4.0f64, 0.25]).recip_sqrt_precise();
+    assert!(precise.all_approx_eq(Double::new([0.5, 2.0]), 1e-12));
+
+    let edges = Double::new([0.0f32, f32::INFINITY]).recip_sqrt_precise();
+    assert_eq!(edges.into_inner(), [f32::INFINITY, 0.0]);
+}
+
+#[test]
+fn sum_product_ref() {
+    let points = [
+        Double::new([1, 2]),
+        Double::new([3, 4]),
+        Double::new([5, 6]),
+    ];
+    assert_eq!(points.iter().sum::<Double<i32>>(), Double::new([9, 12]));
+    assert_eq!(
+        points.iter().product::<Double<i32>>(),
+        Double::new([15, 48])
+    );
+}
+
+#[test]
+fn mask_fold() {
+    let masks = [
+        QuadMask::<i32>::new([true, true, false, true]),
+        QuadMask::new([true, false, false, true]),
+        QuadMask::new([true, true, false, false]),
+    ];
+
+    assert_eq!(
+        QuadMask::fold_and(masks.iter().copied()).into_inner(),
+        [true, false, false, false]
+    );
+    assert_eq!(
+        QuadMask::fold_or(masks.iter().copied()).into_inner(),
+        [true, true, false, true]
+    );
+    assert!(QuadMask::<i32>::fold_and(None).all());
+}
+
+#[test]
+fn from_fn() {
+    assert_eq!(Quad::from_fn(|i| i as i32 * 2), Quad::new([0, 2, 4, 6]));
+    assert_eq!(Double::from_fn(|i| i as f32), Double::new([0.0, 1.0]));
+}
+
+#[test]
+fn apply() {
+    let original = Quad::new([1, 2, 3, 4]);
+    let mut q = original;
+    q.apply(|x| x + 1);
+    assert_eq!(q, original + Quad::splat(1));
+
+    let mut d = Double::new([1.5f32, -2.0]);
+    d.apply(|x| x * 2.0);
+    assert_eq!(d, Double::new([3.0, -4.0]));
+}
+
+#[test]
+fn shift_scalar() {
+    assert_eq!(Quad::<u32>::splat(1).shl_scalar(4), Quad::splat(16));
+    assert_eq!(Quad::<u32>::splat(256).shr_scalar(4), Quad::splat(16));
+    assert_eq!(
+        Double::<u8>::new([0b0000_0011, 0b1000_0000]).shl_scalar(1),
+        Double::new([0b0000_0110, 0])
+    );
+}
+
+#[test]
+fn shr_sign_extension() {
+    assert_eq!(Quad::<i32>::splat(-16).shr_scalar(2), Quad::splat(-4));
+    assert_eq!(
+        Quad::<u32>::splat(0xFFFF_FFF0).shr_scalar(2),
+        Quad::splat(0x3FFF_FFFC)
+    );
+
+    assert_eq!(
+        Quad::<i32>::new([-16, -1, 16, i32::MIN]) >> Quad::splat(2),
+        Quad::new([-4, -1, 4, i32::MIN / 4])
+    );
+    assert_eq!(
+        Double::<u32>::new([0x8000_0000, 16]) >> Double::splat(2),
+        Double::new([0x2000_0000, 4])
+    );
+}
+
+#[test]
+fn select() {
+    // Golden values, which must match on every backend bit-for-bit.
+    let a = Quad::new([f32::from_bits(0x7fc0_0001), -0.0, 1.5, f32::INFINITY]);
+    let b = Quad::new([2.0, 0.0, f32::from_bits(0xffc0_0002), -3.25]);
+    let mask = QuadMask::new([true, false, false, true]);
+
+    let picked = where_true(mask, a, b).into_inner();
+    let bits = [
+        picked[0].to_bits(),
+        picked[1].to_bits(),
+        picked[2].to_bits(),
+        picked[3].to_bits(),
+    ];
+    assert_eq!(bits, [0x7fc0_0001, 0x0000_0000, 0xffc0_0002, 0x7f80_0000]);
+    assert_eq!(mask.select(a, b).into_inner()[3], f32::INFINITY);
+
+    let mask = DoubleMask::new([false, true]);
+    assert_eq!(
+        where_true(mask, Double::new([1, 2]), Double::new([3, 4])),
+        Double::new([3, 2])
+    );
+}
+
+#[test]
+fn minmax() {
+    assert_eq!(Quad::new([3, 1, 4, 2]).minmax(), (1, 4));
+    assert_eq!(Double::new([-1.5f32, -2.5]).minmax(), (-2.5, -1.5));
+    assert_eq!(
+        Quad::new([1.0f32, f32::NAN, -1.0, 2.0]).minmax(),
+        (-1.0, 2.0)
+    );
+}
+
+#[test]
+fn wrapping_neg() {
+    assert_eq!(
+        Quad::<i32>::splat(i32::MIN).wrapping_neg(),
+        Quad::splat(i32::MIN)
+    );
+    assert_eq!(
+        Quad::<u32>::new([0, 1, 2, u32::MAX]).wrapping_neg(),
+        Quad::new([0, u32::MAX, u32::MAX - 1, 1])
+    );
+    assert_eq!(
+        Double::<i8>::new([5, -7]).wrapping_neg(),
+        Double::new([-5, 7])
+    );
+}
+
+#[test]
+fn rotate() {
+    let point = Double::new([1.0f32, 0.0]);
+    assert!(point
+        .rotate(0.0, 1.0)
+        .all_approx_eq(Double::new([0.0, 1.0]), 1e-6));
+    assert!(point
+        .rotate_by_angle(core::f32::consts::FRAC_PI_2)
+        .all_approx_eq(Double::new([0.0, 1.0]), 1e-6));
+
+    let point = Double::new([3.0f64, -4.0]);
+    assert_eq!(point.rotate_by_angle(0.0), point);
+    assert_eq!(Double::new([2, 3]).rotate(0, -1), Double::new([3, -2]));
+}
+
+#[test]
+fn clamp_scalar() {
+    assert_eq!(
+        Quad::new([-5, 0, 5, 10]).clamp_scalar(0, 6),
+        Quad::new([0, 0, 5, 6])
+    );
+    assert_eq!(
+        Quad::new([-0.5f32, 0.2, 1.5, 1.0]).clamp01(),
+        Quad::new([0.0, 0.2, 1.0, 1.0])
+    );
+    assert_eq!(
+        Double::new([2.0f64, -1.0]).clamp01(),
+        Double::new([1.0, 0.0])
+    );
+}
+
+#[test]
+fn cmp_eq_mask_int() {
+    let a = Quad::new([1.0f32, 2.0, 3.0, 4.0]);
+    assert_eq!(a.cmp_eq_mask_int(a), Quad::splat(-1));
+    assert_eq!(
+        a.cmp_eq_mask_int(Quad::new([1.0, 0.0, 3.0, f32::NAN])),
+        Quad::new([-1, 0, -1, 0])
+    );
+    assert_eq!(
+        Double::<u64>::new([7, 8]).cmp_eq_mask_int(Double::new([7, 9])),
+        Double::new([-1, 0])
+    );
+    assert_eq!(
+        QuadMask::<u8>::new([true, false, false, true]).to_int(),
+        Quad::new([-1, 0, 0, -1])
+    );
+}
+
+#[test]
+fn dot4_length4() {
+    let a = Quad::new([1.0f32, 2.0, 3.0, 4.0]);
+    let b = Quad::new([4.0f32, 3.0, 2.0, 1.0]);
+    assert_eq!(a.reduce_sum(), 10.0);
+    assert_eq!(a.dot4(b), 20.0);
+    assert_eq!(Quad::new([1.0f32, 1.0, 1.0, 1.0]).length4(), 2.0);
+    assert_eq!(Quad::new([1u8, 2, 3, 4]).reduce_sum(), 10);
+    assert_eq!(Double::new([3i32, -5]).reduce_sum(), -2);
+}
+
+#[test]
+fn from_iter_or() {
+    assert_eq!(Quad::from_iter_or(vec![3, 4], 0), Quad::new([3, 4, 0, 0]));
+    assert_eq!(Quad::from_iter_or(1..10, 0), Quad::new([1, 2, 3, 4]));
+    assert_eq!(
+        Double::from_iter_or(core::iter::empty(), 1.5f32),
+        Double::splat(1.5)
+    );
+}
+
+#[test]
+fn sanitize() {
+    let a = Quad::new([1.0f32, f32::NAN, f32::INFINITY, -2.0]);
+    assert_eq!(a.is_nan(), QuadMask::new([false, true, false, false]));
+    assert_eq!(a.is_finite(), QuadMask::new([true, false, false, true]));
+    assert_eq!(a.sanitize(0.0), Quad::new([1.0, 0.0, 0.0, -2.0]));
+    assert_eq!(
+        Double::new([f64::NEG_INFINITY, 3.0]).sanitize(-1.0),
+        Double::new([-1.0, 3.0])
+    );
+}
+
+#[test]
+fn sum_product_identity() {
+    assert_eq!(
+        core::iter::empty::<Quad<i32>>().sum::<Quad<i32>>(),
+        Quad::splat(0)
+    );
+    assert_eq!(
+        core::iter::empty::<Quad<i32>>().product::<Quad<i32>>(),
+        Quad::splat(1)
+    );
+    assert_eq!(
+        core::iter::empty::<&Double<f32>>().sum::<Double<f32>>(),
+        Double::splat(0.0)
+    );
+    assert_eq!(
+        core::iter::empty::<&Double<f32>>().product::<Double<f32>>(),
+        Double::splat(1.0)
+    );
+    assert_eq!(Quad::new([1, 2, 3, 4]).sum_lanes(), 10);
+}
+
+#[test]
+fn component_accessors() {
+    let mut q = Quad::new([1, 2, 3, 4]);
+    assert_eq!((q.x(), q.y(), q.z(), q.w()), (1, 2, 3, 4));
+    q.set_z(30);
+    q.set_w(40);
+    assert_eq!(q, Quad::new([1, 2, 30, 40]));
+
+    let mut d = Double::new([1.0f32, 2.0]);
+    d.set_x(5.0);
+    assert_eq!((d.x(), d.y()), (5.0, 2.0));
+}
+
+#[test]
+fn with_lane() {
+    let a = Quad::new([1, 2, 3, 4]);
+    assert_eq!(a.with_lane(2, 99), Quad::new([1, 2, 99, 4]));
+    assert_eq!(a, Quad::new([1, 2, 3, 4]));
+    assert_eq!(Double::new([1, 2]).with_lane(0, 7), Double::new([7, 2]));
+}
+
+#[test]
+#[should_panic]
+fn with_lane_out_of_bounds() {
+    let _ = Double::new([1, 2]).with_lane(2, 3);
+}
+
+#[test]
+fn has_nan_is_all_finite() {
+    let a = Quad::new([1.0f32, f32::NAN, 3.0, 4.0]);
+    assert!(a.has_nan());
+    assert!(!a.is_all_finite());
+
+    let b = Double::new([1.0f64, f64::INFINITY]);
+    assert!(!b.has_nan());
+    assert!(!b.is_all_finite());
+    assert!(Double::new([1.0f64, -2.0]).is_all_finite());
+}
+
+#[test]
+fn from_slice_unchecked() {
+    let data = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let a = unsafe { Quad::from_slice_unchecked(&data[1..5]) };
+    assert_eq!(a, Quad::new([2.0, 3.0, 4.0, 5.0]));
+    let b = unsafe { Double::from_slice_unchecked(&data[4..]) };
+    assert_eq!(b, Double::new([5.0, 6.0]));
+}
+
+#[test]
+fn aligned_load_store() {
+    #[repr(align(16))]
+    struct Aligned([f32; 8]);
+
+    let mut buf = Aligned([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    let a = unsafe { Quad::from_aligned(&buf.0[4..]) };
+    assert_eq!(a, Quad::new([5.0, 6.0, 7.0, 8.0]));
+
+    unsafe { (a * Quad::splat(2.0)).store_aligned(&mut buf.0[..4]) };
+    assert_eq!(buf.0, [10.0, 12.0, 14.0, 16.0, 5.0, 6.0, 7.0, 8.0]);
+
+    let b = unsafe { Double::from_aligned(&buf.0[2..]) };
+    assert_eq!(b, Double::new([14.0, 16.0]));
+}
+
+#[test]
+fn mask_eq_hash() {
+    use std::collections::HashSet;
+
+    let mut set = HashSet::new();
+    set.insert(QuadMask::<f32>::new([true, false, true, false]));
+    set.insert(QuadMask::new([true, false, true, false]));
+    set.insert(QuadMask::new([false, false, false, false]));
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&Quad::new([1.0f32, 0.0, 1.0, 0.0]).packed_eq(Quad::splat(1.0))));
+
+    let mut set = HashSet::new();
+    set.insert(DoubleMask::<u8>::new([true, false]));
+    assert!(!set.contains(&DoubleMask::new([false, true])));
+}
+
+#[test]
+fn reduce_product() {
+    assert_eq!(Quad::new([1, 2, 3, 4]).reduce_product(), 24);
+    assert_eq!(Double::new([1.5f32, -2.0]).reduce_product(), -3.0);
+    assert_eq!(Quad::new([2.0f64, 3.0, 4.0, 0.5]).reduce_product(), 12.0);
+}
+
+#[test]
+fn masked_arithmetic() {
+    let a = Quad::new([1, 2, 3, 4]);
+    let mask = QuadMask::new([true, false, true, false]);
+    assert_eq!(a.add_where(Quad::splat(1), mask), Quad::new([2, 2, 4, 4]));
+    assert_eq!(a.sub_where(Quad::splat(1), mask), Quad::new([0, 2, 2, 4]));
+    assert_eq!(
+        a.mul_where(Quad::splat(10), !mask),
+        Quad::new([1, 20, 3, 40])
+    );
+
+    let b = Double::new([1.0f32, 2.0]);
+    assert_eq!(
+        b.add_where(Double::splat(0.5), DoubleMask::new([false, true])),
+        Double::new([1.0, 2.5])
+    );
+}
+
+#[cfg(feature = "nightly")]
+#[test]
+fn simd_interop() {
+    use core::simd::Simd;
+
+    let simd = Simd::from_array([1.0f32, 2.0, 3.0, 4.0]);
+    let quad = Quad::from(simd);
+    assert_eq!(quad, Quad::new([1.0, 2.0, 3.0, 4.0]));
+    assert_eq!(quad.as_simd(), simd);
+    assert_eq!(Simd::from(quad), simd);
+
+    let double = Double::from(Simd::from_array([5u8, 6]));
+    assert_eq!(Simd::from(double).to_array(), [5, 6]);
+}
+
+#[test]
+fn mask_from_bools() {
+    assert_eq!(
+        QuadMask::<f32>::from_bools(true, false, false, true),
+        QuadMask::new([true, false, false, true])
+    );
+    assert_eq!(
+        DoubleMask::<i32>::from_bools(false, true),
+        DoubleMask::new([false, true])
+    );
+}
+
+#[test]
+fn square_cube() {
+    assert_eq!(Quad::new([2, 3, 4, 5]).square(), Quad::new([4, 9, 16, 25]));
+    assert_eq!(
+        Quad::new([2, -3, 4, 5]).cube(),
+        Quad::new([8, -27, 64, 125])
+    );
+    assert_eq!(
+        Double::new([1.5f32, -2.0]).square(),
+        Double::new([2.25, 4.0])
+    );
+}
+
+#[test]
+fn smoothstep() {
+    let edge0 = Quad::splat(1.0f32);
+    let edge1 = Quad::splat(3.0f32);
+    assert_eq!(
+        Quad::new([0.0f32, 1.0, 2.0, 3.0]).smoothstep(edge0, edge1),
+        Quad::new([0.0, 0.0, 0.5, 1.0])
+    );
+    assert_eq!(
+        Quad::splat(4.0f32).smoothstep(edge0, edge1),
+        Quad::splat(1.0)
+    );
+
+    let mut last = 0.0;
+    for i in 0..=20 {
+        let x = 1.0 + i as f32 / 10.0;
+        let y = Double::splat(x)
+            .smoothstep(Double::splat(1.0), Double::splat(3.0))
+            .x();
+        assert!(y >= last);
+        last = y;
+    }
+    assert_eq!(last, 1.0);
+}
+
+#[test]
+fn checked_div() {
+    let a = Quad::new([10, 20, 30, 40]);
+    assert_eq!(
+        a.checked_div(Quad::new([2, 4, 5, 8])),
+        Some(Quad::new([5, 5, 6, 5]))
+    );
+    assert_eq!(a.checked_div(Quad::new([1, 0, 1, 1])), None);
+    assert_eq!(
+        Double::new([i8::MIN, 1]).checked_div(Double::splat(-1)),
+        None
+    );
+}
+
+#[test]
+fn mask_nand_nor_xnor() {
+    let t = QuadMask::<f32>::splat(true);
+    assert_eq!(t.nand(t), QuadMask::splat(false));
+
+    let a = QuadMask::<f32>::new([true, true, false, false]);
+    let b = QuadMask::new([true, false, true, false]);
+    assert_eq!(a.nand(b), QuadMask::new([false, true, true, true]));
+    assert_eq!(a.nor(b), QuadMask::new([false, false, false, true]));
+    assert_eq!(a.xnor(b), QuadMask::new([true, false, false, true]));
+
+    let c = DoubleMask::<u32>::new([true, false]);
+    assert_eq!(c.xnor(c), DoubleMask::splat(true));
+}
+
+#[test]
+fn reduce_sum_wide() {
+    assert_eq!(Quad::<u8>::splat(200).reduce_sum_wide(), 800u32);
+    assert_eq!(Quad::<i16>::splat(i16::MIN).reduce_sum_wide(), -131_072i32);
+    assert_eq!(Double::new([u32::MAX, 1]).reduce_sum_wide(), 1u64 << 32);
+    assert_eq!(Double::new([1.5f32, 2.0]).reduce_sum_wide(), 3.5f64);
+}
+
+#[test]
+fn dot_fma() {
+    fn kahan_dot(a: [f32; 4], b: [f32; 4]) -> f32 {
+        let (mut sum, mut c) = (0.0f32, 0.0f32);
+        for i in 0..4 {
+            let y = a[i] * b[i] - c;
+            let t = sum + y;
+            c = (t - sum) - y;
+            sum = t;
+        }
+        sum
+    }
+
+    let a = [1.0e4f32, 3.3, -1.0e4, 0.7];
+    let b = [1.0001f32, 2.2, 1.0, 9.1];
+    let fma = Quad::new(a).dot_fma(Quad::new(b));
+    assert!((fma - kahan_dot(a, b)).abs() <= 1.0e-3);
+
+    assert_eq!(
+        Double::new([2.0f64, 3.0]).dot_fma(Double::new([4.0, 5.0])),
+        23.0
+    );
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn bytemuck_cast_slice() {
+    let mut data = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+    let quads = Quad::cast_slice(&data);
+    assert_eq!(quads.len(), 2);
+    assert_eq!(quads[1], Quad::new([5.0, 6.0, 7.0, 8.0]));
+
+    assert_eq!(Double::cast_slice(&data[..7]).len(), 3);
+
+    for quad in Quad::cast_slice_mut(&mut data) {
+        *quad *= Quad::splat(2.0);
+    }
+    assert_eq!(data, [2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0]);
+}
+
+#[test]
+fn iota() {
+    assert_eq!(Quad::iota(0, 2), Quad::new([0, 2, 4, 6]));
+    assert_eq!(Quad::iota(252u8, 1), Quad::new([252, 253, 254, 255]));
+    assert_eq!(Double::iota(1.0f32, -0.5), Double::new([1.0, 0.5]));
+}
+
+#[test]
+fn wrapping_shifts() {
+    assert_eq!(Quad::<u32>::splat(1).wrapping_shl(33), Quad::splat(2));
+    assert_eq!(Quad::<u32>::splat(8).wrapping_shr(34), Quad::splat(2));
+    assert_eq!(Double::<i8>::splat(-64).wrapping_shr(13), Double::splat(-2));
+    assert_eq!(Double::<u64>::splat(3).wrapping_shl(64), Double::splat(3));
+}
+
+#[test]
+fn mask_lanes() {
+    let mask = QuadMask::<f32>::new([true, false, true, false]);
+    assert_eq!(mask.lanes().collect::<Vec<_>>(), [true, false, true, false]);
+    assert_eq!(
+        mask.lanes()
+            .enumerate()
+            .filter(|&(_, set)| set)
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>(),
+        [0, 2]
+    );
+    assert_eq!(DoubleMask::<u8>::splat(true).lanes().count(), 2);
+}
+
+#[test]
+fn mask_first_last_set() {
+    let mask = QuadMask::<f32>::new([false, true, false, true]);
+    assert_eq!(mask.first_set(), Some(1));
+    assert_eq!(mask.last_set(), Some(3));
+    assert_eq!(QuadMask::<f32>::splat(false).first_set(), None);
+    assert_eq!(DoubleMask::<i32>::new([true, false]).last_set(), Some(0));
+    assert_eq!(DoubleMask::<i32>::splat(false).last_set(), None);
+}
+
+#[test]
+fn min_max_scalar() {
+    let a = Quad::new([1, 5, 3, 7]);
+    assert_eq!(a.min_scalar(4), Quad::new([1, 4, 3, 4]));
+    assert_eq!(a.max_scalar(4), Quad::new([4, 5, 4, 7]));
+    assert_eq!(
+        Double::new([-1.0f32, 2.0]).max_scalar(0.0),
+        Double::new([0.0, 2.0])
+    );
+}
+
+#[test]
+fn abs_real() {
+    assert_eq!(
+        Double::new([-1.5f64, 2.5]).abs_real(),
+        Double::new([1.5, 2.5])
+    );
+    assert_eq!(Double::new([-1.5f64, 2.5]).abs(), Double::new([1.5, 2.5]));
+
+    let a = Quad::new([-0.0f32, -3.0, f32::NEG_INFINITY, 4.0]).abs_real();
+    assert_eq!(a, Quad::new([0.0, 3.0, f32::INFINITY, 4.0]));
+    assert!(a[0].is_sign_positive());
+}
+
+#[test]
+fn swap_bytes() {
+    assert_eq!(
+        Quad::<u32>::splat(0x0000_00FF).swap_bytes(),
+        Quad::splat(0xFF00_0000)
+    );
+    let a = Double::new([0x1234u16, 0xABCD]);
+    assert_eq!(a.swap_bytes(), Double::new([0x3412, 0xCDAB]));
+    assert_eq!(
+        a.to_be(),
+        Double::new([0x1234u16.to_be(), 0xABCDu16.to_be()])
+    );
+    assert_eq!(
+        a.to_le(),
+        Double::new([0x1234u16.to_le(), 0xABCDu16.to_le()])
+    );
+}
+
+#[test]
+fn select_sign() {
+    let a = Quad::new([-1.0f32, 0.0, 2.0, -3.0]);
+    let picked = a.select_sign(Quad::splat(-10.0), Quad::splat(0.5), Quad::splat(10.0));
+    assert_eq!(picked, Quad::new([-10.0, 0.5, 10.0, -10.0]));
+
+    let b = Double::new([f32::NAN, -0.0]);
+    assert_eq!(
+        b.select_sign(Double::splat(1.0), Double::splat(2.0), Double::splat(3.0)),
+        Double::splat(2.0)
+    );
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn f16_round_trip() {
+    let a = Quad::new([1.0f32, -0.5, 65504.0, 0.099_975_586]);
+    let half = a.to_f16();
+    assert_eq!(half[1], half::f16::from_f32(-0.5));
+    assert_eq!(Quad::from_f16(half), a);
+}
+
+#[test]
+fn transform_2x2() {
+    let p = Double::new([3.0f32, -2.0]);
+    assert_eq!(p.transform_2x2(Quad::new([1.0, 0.0, 0.0, 1.0])), p);
+    assert_eq!(
+        p.transform_2x2(Quad::new([1.0, 2.0, 3.0, 4.0])),
+        Double::new([-1.0, 1.0])
+    );
+    assert_eq!(
+        Double::new([1, 2]).transform_2x2(Quad::new([0, -1, 1, 0])),
+        Double::new([-2, 1])
+    );
+}
+
+#[test]
+fn cast_saturating() {
+    assert_eq!(
+        Quad::<i32>::new([-5, 100, 300, 42]).cast_saturating::<u8>(),
+        Quad::new([0, 100, 255, 42])
+    );
+    assert_eq!(
+        Double::<u64>::new([u64::MAX, 7]).cast_saturating::<i16>(),
+        Double::new([i16::MAX, 7])
+    );
+    assert_eq!(
+        Double::<i8>::new([i8::MIN, -1]).cast_saturating::<i64>(),
+        Double::new([-128, -1])
+    );
+}
+
+#[test]
+fn eq_array() {
+    assert!(Quad::new([1, 2, 3, 4]) == [1, 2, 3, 4]);
+    assert!(Quad::new([1, 2, 3, 4]) != [1, 2, 3, 5]);
+    assert_eq!(Double::new([0.5f32, 1.5]), [0.5, 1.5]);
+    assert_ne!(Double::new([f32::NAN, 1.0]), [f32::NAN, 1.0]);
+}
+
+#[test]
+fn prefix_sum() {
+    assert_eq!(Quad::new([1, 2, 3, 4]).prefix_sum(), [1, 3, 6, 10]);
+    assert_eq!(Double::new([0.5f32, 0.25]).prefix_sum(), [0.5, 0.75]);
+}
+
+#[test]
+fn into_tuple() {
+    let (x, y) = Double::new([1, 2]).into_tuple();
+    assert_eq!([x, y], Double::new([1, 2]).into_inner());
+
+    let q = Quad::new([1.0f32, 2.0, 3.0, 4.0]);
+    let (a, b, c, d) = q.into_tuple();
+    assert_eq!([a, b, c, d], q.into_inner());
+}
+
+#[test]
+fn min_max_with_mask() {
+    let a = Quad::new([1, 5, 3, 7]);
+    let b = Quad::new([4, 2, 6, 0]);
+
+    let (min, mask) = a.min_with_mask(b);
+    assert_eq!(min, [1, 2, 3, 0]);
+    assert_eq!(mask, QuadMask::new([true, false, true, false]));
+
+    let (max, mask) = a.max_with_mask(b);
+    assert_eq!(max, [4, 5, 6, 7]);
+    assert_eq!(mask, QuadMask::new([false, true, false, true]));
+}
+
+#[cfg(feature = "rkyv")]
+#[test]
+fn rkyv_archive() {
+    use rkyv::Deserialize;
+
+    let quad = Quad::new([1.0f32, 2.0, 3.0, 4.0]);
+    let bytes = rkyv::to_bytes::<_, 256>(&quad).unwrap();
+    let archived = unsafe { rkyv::archived_root::<Quad<f32>>(&bytes) };
+    assert_eq!(*archived, [1.0, 2.0, 3.0, 4.0]);
+
+    let back: Quad<f32> = archived.deserialize(&mut rkyv::Infallible).unwrap();
+    assert_eq!(back, quad);
+}
+
+#[test]
+fn mask_to_numeric() {
+    let mask = QuadMask::<f32>::new([true, false, true, true]);
+    assert_eq!(mask.to_quad::<i32>(), [1, 0, 1, 1]);
+    assert_eq!(mask.to_quad::<u8>().reduce_sum(), 3);
+    assert_eq!(
+        DoubleMask::<u8>::new([false, true]).to_double::<f64>(),
+        [0.0, 1.0]
+    );
+}
+
+#[test]
+fn round_ties_even() {
+    assert_eq!(
+        Quad::new([0.5f32, 1.5, 2.5, 3.5]).round_ties_even(),
+        [0.0, 2.0, 2.0, 4.0]
+    );
+    assert_eq!(
+        Quad::new([-0.5f64, -1.5, 2.4, -2.6]).round_ties_even(),
+        [0.0, -2.0, 2.0, -3.0]
+    );
+    assert_eq!(Double::new([1e20f32, 7.0]).round_ties_even(), [1e20, 7.0]);
+}
+
+#[test]
+fn trunc() {
+    assert_eq!(
+        Quad::new([1.7f32, -1.7, 0.5, -0.0]).trunc(),
+        [1.0, -1.0, 0.0, 0.0]
+    );
+    assert_eq!(
+        Quad::new([2.5f32, -2.5, 3.9, -3.1]).floor(),
+        [2.0, -3.0, 3.0, -4.0]
+    );
+    assert_eq!(
+        Quad::new([2.5f32, -2.5, 3.9, -3.1]).ceil(),
+        [3.0, -2.0, 4.0, -3.0]
+    );
+    assert_eq!(Double::new([9.99f64, -9.99]).trunc(), [9.0, -9.0]);
+}
+
+#[test]
+fn zero_one() {
+    assert_eq!(Quad::<i32>::zero(), Quad::splat(0));
+    assert_eq!(Quad::<i32>::one(), Quad::splat(1));
+    assert_eq!(Double::<f32>::zero(), [0.0, 0.0]);
+    assert_eq!(Double::<f64>::one(), [1.0, 1.0]);
+}
+
+#[test]
+fn powf_fast() {
+    let mut worst = 0.0f32;
+    for i in 1..200 {
+        let base = i as f32 * 0.173;
+        let e = Quad::new([0.5f32, 2.2, -1.3, 4.0]);
+        let fast = Quad::splat(base).powf_fast(e);
+        for lane in 0..4 {
+            let exact = base.powf(e[lane]);
+            worst = worst.max(((fast[lane] - exact) / exact).abs());
+        }
+    }
+    assert!(worst < 1.0e-5, "relative error {}", worst);
+
+    assert_eq!(
+        Quad::splat(2.0f32).powf_fast(Quad::splat(10.0))[0].round(),
+        1024.0
+    );
+}
+
+#[test]
+fn mask_prefer() {
+    let high = QuadMask::<f32>::new([true, false, false, false]);
+    let mid = QuadMask::new([false, true, false, true]);
+    let low = QuadMask::new([true, true, true, false]);
+    assert_eq!(
+        high.prefer(mid).prefer(low),
+        QuadMask::new([true, true, true, true])
+    );
+    assert_eq!(
+        DoubleMask::<u8>::splat(false).prefer(DoubleMask::new([false, true])),
+        DoubleMask::new([false, true])
+    );
+}
+
+#[test]
+fn recip_fast_refined() {
+    let a = Quad::new([0.3f32, 1.0, 7.5, -123.0]);
+    let exact = a.recip();
+    let fast = a.recip_fast();
+    let refined = a.recip_refined(2);
+    for i in 0..4 {
+        assert!(((fast[i] - exact[i]) / exact[i]).abs() < 1.0e-3);
+        assert!(((refined[i] - exact[i]) / exact[i]).abs() < 1.0e-6);
+    }
+
+    assert_eq!(Double::new([2.0f64, 4.0]).recip_refined(0), [0.5, 0.25]);
+
+    let edges = Quad::new([0.0f32, f32::INFINITY, 4.0, -0.0]).recip_refined(2);
+    assert_eq!(
+        edges.into_inner(),
+        [f32::INFINITY, 0.0, 0.25, f32::NEG_INFINITY]
+    );
+}
+
+#[test]
+fn broadcast_first() {
+    assert_eq!(Quad::new([5, 1, 2, 3]).broadcast_first(), [5, 5, 5, 5]);
+    assert_eq!(Double::new([0.5f32, 1.0]).broadcast_first(), [0.5, 0.5]);
+}
+
+#[test]
+fn narrow_wrapping() {
+    use core::num::Wrapping;
+
+    let a = Quad::new([Wrapping(250u8), Wrapping(0), Wrapping(128), Wrapping(1)]);
+    let b = Quad::splat(Wrapping(10u8));
+    assert_eq!(
+        (a + b).into_inner(),
+        [Wrapping(4), Wrapping(10), Wrapping(138), Wrapping(11)]
+    );
+    assert_eq!(
+        (a - b).into_inner(),
+        [Wrapping(240), Wrapping(246), Wrapping(118), Wrapping(247)]
+    );
+
+    let c = Double::new([Wrapping(i16::MAX), Wrapping(i16::MIN)]);
+    assert_eq!(
+        (c * Double::splat(Wrapping(2))).into_inner(),
+        [Wrapping(-2), Wrapping(0)]
+    );
+    assert_eq!(
+        Quad::<i8>::splat(i8::MIN).wrapping_neg(),
+        Quad::splat(i8::MIN)
+    );
+}
+
+#[test]
+fn interleave_doubles() {
+    let a = Double::new([1, 2]);
+    let b = Double::new([3, 4]);
+    let q = Quad::interleave_doubles(a, b);
+    assert_eq!(q, [1, 3, 2, 4]);
+    assert_eq!(q.deinterleave_doubles(), (a, b));
+    assert_eq!(
+        Quad::new([1.0f32, 2.0, 3.0, 4.0]).deinterleave_doubles(),
+        (Double::new([1.0, 3.0]), Double::new([2.0, 4.0]))
+    );
+}
+
+#[test]
+fn mat4_mul() {
+    let identity = [
+        Quad::new([1.0f32, 0.0, 0.0, 0.0]),
+        Quad::new([0.0, 1.0, 0.0, 0.0]),
+        Quad::new([0.0, 0.0, 1.0, 0.0]),
+        Quad::new([0.0, 0.0, 0.0, 1.0]),
+    ];
+    let v = Quad::new([1.0f32, -2.0, 3.5, 1.0]);
+    assert_eq!(v.mat4_mul(identity), v);
+
+    // 90 degree rotation around the Z axis.
+    let rotate_z = [
+        Quad::new([0.0f32, -1.0, 0.0, 0.0]),
+        Quad::new([1.0, 0.0, 0.0, 0.0]),
+        Quad::new([0.0, 0.0, 1.0, 0.0]),
+        Quad::new([0.0, 0.0, 0.0, 1.0]),
+    ];
+    assert_eq!(
+        Quad::new([1.0f32, 0.0, 0.0, 1.0]).mat4_mul(rotate_z),
+        [0.0, 1.0, 0.0, 1.0]
+    );
+
+    let rows = [
+        Quad::new([1, 2, 3, 4]),
+        Quad::new([5, 6, 7, 8]),
+        Quad::new([9, 10, 11, 12]),
+        Quad::new([13, 14, 15, 16]),
+    ];
+    let v = Quad::new([1, 0, -1, 2]);
+    assert_eq!(v.mat4_mul(rows), Quad::from_fn(|i| rows[i].dot4(v)));
+}
+
+#[test]
+fn debug_alternate_backend() {
+    use core::num::Wrapping;
+
+    let simd = format!("{:#?}", Quad::<u32>::splat(1));
+    let naive = format!("{:#?}", Quad::splat(Wrapping(1u32)));
+
+    if cfg!(feature = "nightly") {
+        assert!(simd.starts_with("simd "), "{}", simd);
+        assert!(naive.starts_with("naive "), "{}", naive);
+    } else {
+        assert!(!simd.contains("simd"), "{}", simd);
+        assert!(!naive.contains("naive"), "{}", naive);
+    }
+}
+
+#[test]
+fn ord_matches_naive() {
+    use core::num::Wrapping;
+
+    // A small xorshift generator, so the test is deterministic.
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        // Keep the range small so that equal prefixes are common.
+        (state % 5) as i32 - 2
+    };
+
+    for _ in 0..10_000 {
+        let a = [next(), next(), next(), next()];
+        let b = [next(), next(), next(), next()];
+
+        // `Wrapping<i32>` is never optimized using SIMD, so it always uses the naive backend.
+        let simd = Quad::new(a).cmp(&Quad::new(b));
+        let naive = Quad::new(a.map(Wrapping)).cmp(&Quad::new(b.map(Wrapping)));
+        assert_eq!(simd, a.cmp(&b), "{:?} vs {:?}", a, b);
+        assert_eq!(naive, simd, "{:?} vs {:?}", a, b);
+        assert_eq!(
+            Quad::new(a).partial_cmp(&Quad::new(b)),
+            Some(simd),
+            "{:?} vs {:?}",
+            a,
+            b
+        );
+
+        let (a, b) = ([a[0], a[1]], [b[0], b[1]]);
+        assert_eq!(Double::new(a).cmp(&Double::new(b)), a.cmp(&b));
+    }
+}
+
+#[test]
+fn from_array_ref_mut() {
+    #[repr(align(16))]
+    struct Aligned([f32; 4]);
+
+    let mut array = Aligned([1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(
+        *Quad::from_array_ref(&array.0).unwrap(),
+        [1.0, 2.0, 3.0, 4.0]
+    );
+
+    *Quad::from_array_mut(&mut array.0).unwrap() *= Quad::splat(2.0);
+    assert_eq!(array.0, [2.0, 4.0, 6.0, 8.0]);
+
+    #[repr(align(16))]
+    struct AlignedPair([u8; 2]);
+
+    let mut pair = AlignedPair([1, 2]);
+    Double::from_array_mut(&mut pair.0).unwrap()[1] = 7;
+    assert_eq!(pair.0, [1, 7]);
+
+    // An array that isn't aligned for the SIMD vector is refused instead of panicking.
+    #[repr(align(16))]
+    struct Wide([f32; 8]);
+
+    let wide = Wide([0.0; 8]);
+    // SAFETY: `wide.0[1..5]` is in bounds, and `[f32; 4]` has the same alignment as `f32`.
+    let shifted = unsafe { &*(wide.0[1..].as_ptr() as *const [f32; 4]) };
+    assert_eq!(
+        Quad::from_array_ref(shifted).is_some(),
+        std::mem::align_of::<Quad<f32>>() <= std::mem::align_of::<f32>()
+    );
+}
+
+#[test]
+fn iter_mut() {
+    let mut q = Quad::new([1, 2, 3, 4]);
+    for x in q.iter_mut() {
+        *x *= 2;
+    }
+    assert_eq!(q.into_inner(), [2, 4, 6, 8]);
+
+    let mut d = Double::new([1.5f32, -1.0]);
+    d.iter_mut().for_each(|x| *x += 1.0);
+    assert_eq!(d.into_inner(), [2.5, 0.0]);
+
+    for x in &mut q {
+        *x += 1;
+    }
+    assert_eq!(q.into_inner(), [3, 5, 7, 9]);
+}
+
+#[test]
+fn reduce_sum_ordered() {
+    let lanes = [1.0e8f32, 1.0, -1.0e8, 1.0];
+    let expected = ((lanes[0] + lanes[1]) + lanes[2]) + lanes[3];
+    assert_eq!(
+        Quad::new(lanes).reduce_sum_ordered().to_bits(),
+        expected.to_bits()
+    );
+
+    let lanes = [0.1f64, 0.2];
+    assert_eq!(
+        Double::new(lanes).reduce_sum_ordered().to_bits(),
+        (lanes[0] + lanes[1]).to_bits()
+    );
+    assert_eq!(Quad::new([1, 2, 3, 4]).reduce_sum_ordered(), 10);
+}
+
+#[test]
+fn clamp_length_max() {
+    let v = Double::new([6.0f64, 8.0]).clamp_length_max(5.0);
+    assert!(((v * v).reduce_sum().sqrt() - 5.0).abs() < 1e-12);
+    assert_eq!(v.into_inner(), [3.0, 4.0]);
+
+    let huge = Double::new([3e20f32, 4e20]).clamp_length_max(5.0);
+    assert!(huge.all_approx_eq(Double::new([3.0, 4.0]), 1e-5));
+
+    let short = Quad::new([1.0f32, 0.0, 0.0, 0.0]);
+    assert_eq!(short.clamp_length_max(5.0), short);
+    assert_eq!(
+        Quad::<f32>::splat(0.0).clamp_length_max(5.0).into_inner(),
+        [0.0; 4]
+    );
+}
+
+#[cfg(feature = "num-integer")]
+#[test]
+fn gcd_lcm() {
+    let a = Quad::new([12, 8, 15, 7]);
+    let b = Quad::new([18, 12, 5, 14]);
+    assert_eq!(a.gcd(b).into_inner(), [6, 4, 5, 7]);
+    assert_eq!(a.lcm(b).into_inner(), [36, 24, 15, 14]);
+    assert_eq!(
+        Double::new([0u32, 9]).gcd(Double::new([4, 6])).into_inner(),
+        [4, 3]
+    );
+}
+
+#[test]
+fn swizzles() {
+    let q = Quad::new([1, 2, 3, 4]);
+    assert_eq!(q.xxyy().into_inner(), [1, 1, 2, 2]);
+    assert_eq!(q.xyxy().into_inner(), [1, 2, 1, 2]);
+    assert_eq!(q.zzww().into_inner(), [3, 3, 4, 4]);
+    assert_eq!(q.xyzw(), q);
+
+    let q = Quad::new([1.0f32, 2.0, 3.0, 4.0]);
+    assert_eq!(q.xyxy().into_inner(), [1.0, 2.0, 1.0, 2.0]);
+}
+
+#[test]
+fn extend_truncate() {
+    let q = Double::new([1, 2]).extend(3, 4);
+    assert_eq!(q.into_inner(), [1, 2, 3, 4]);
+    assert_eq!(q.truncate().into_inner(), [1, 2]);
+}
+
+#[test]
+fn mul_add_scalar() {
+    let row = [1.5f32, -2.0, 0.25, 8.0];
+    let acc = [0.5f32, 1.0, -3.0, 2.0];
+    let result = Quad::new(row).mul_add_scalar(3.0, Quad::new(acc));
+    let expected = [
+        row[0].mul_add(3.0, acc[0]),
+        row[1].mul_add(3.0, acc[1]),
+        row[2].mul_add(3.0, acc[2]),
+        row[3].mul_add(3.0, acc[3]),
+    ];
+    assert_eq!(result.into_inner(), expected);
+    assert_eq!(expected, [5.0, -5.0, -2.25, 26.0]);
+}
+
+#[test]
+fn try_from_slice() {
+    use std::convert::TryFrom;
+
+    let q = Quad::try_from(&[1, 2, 3, 4][..]).unwrap();
+    assert_eq!(q.into_inner(), [1, 2, 3, 4]);
+    assert!(Quad::try_from(&[1, 2, 3][..]).is_err());
+    assert!(Quad::try_from(&[1, 2, 3, 4, 5][..]).is_err());
+    assert!(Double::try_from(&[1.0f32][..]).is_err());
+}
+
+#[test]
+fn checked_mul() {
+    assert_eq!(
+        Quad::<i32>::splat(i32::MAX).checked_mul(Quad::splat(2)),
+        None
+    );
+    assert_eq!(
+        Quad::new([1, -2, 3, 4])
+            .checked_mul(Quad::new([5, 6, -7, 8]))
+            .map(Quad::into_inner),
+        Some([5, -12, -21, 32])
+    );
+    assert_eq!(
+        Double::new([1u8, 16]).checked_mul(Double::new([255, 16])),
+        None
+    );
+}
+
+#[test]
+fn lanes_const() {
+    fn width<V: SimdTuple>(_: V) -> usize {
+        V::LANES
+    }
+
+    assert_eq!(Quad::<i32>::LANES, 4);
+    assert_eq!(Double::<f32>::LANES, 2);
+    assert_eq!(Quad::splat(1u8).len(), 4);
+    assert_eq!(Double::splat(1u8).len(), 2);
+    assert_eq!(width(Quad::splat(0.0f64)), 4);
+}
+
+#[test]
+fn shift_parity() {
+    let values = [-0x1234_5678, -1, 1, i32::MAX];
+    let uniform = [3; 4];
+    let varying = [0, 1, 17, 31];
+
+    for &shifts in &[uniform, varying] {
+        let (a, b) = (Quad::new(values), Quad::new(shifts));
+        for i in 0..4 {
+            assert_eq!((a << b)[i], values[i] << shifts[i]);
+            assert_eq!((a >> b)[i], values[i] >> shifts[i]);
+        }
+
+        let unsigned = Quad::from_fn(|i| values[i] as u32);
+        let b = Quad::from_fn(|i| shifts[i] as u32);
+        for i in 0..4 {
+            assert_eq!((unsigned << b)[i], (values[i] as u32) << shifts[i]);
+            assert_eq!((unsigned >> b)[i], (values[i] as u32) >> shifts[i]);
+        }
+    }
+}
+
+#[test]
+fn dot_with_products() {
+    let a = Quad::new([1.0f32, -2.0, 3.5, 0.25]);
+    let b = Quad::new([4.0f32, 0.5, 2.0, -8.0]);
+    assert_eq!(a.mul_lanes(b), a * b);
+
+    let (dot, products) = a.dot_with_products(b);
+    assert_eq!(products, a * b);
+    assert_eq!(dot, a.dot4(b));
+    assert_eq!(dot, 8.0);
+
+    let (dot, products) = Double::new([2, 3]).dot_with_products(Double::new([5, 7]));
+    assert_eq!((dot, products.into_inner()), (31, [10, 21]));
+}
+
+#[test]
+fn mask_count_and_bits() {
+    let all = QuadMask::<f32>::splat(true);
+    assert_eq!((all.count(), all.as_u8()), (4, 0b1111));
+
+    let none = QuadMask::<f32>::splat(false);
+    assert_eq!((none.count(), none.as_u8()), (0, 0));
+
+    let mixed = QuadMask::<i32>::new([true, false, true, true]);
+    assert_eq!((mixed.count(), mixed.as_u8()), (3, 0b1101));
+
+    let mixed = DoubleMask::<u8>::new([false, true]);
+    assert_eq!((mixed.count(), mixed.as_u8()), (1, 0b10));
+    assert_eq!(DoubleMask::<u8>::splat(true).as_u8(), 0b11);
+}
+
+#[test]
+fn get_unchecked() {
+    let mut q = Quad::new([1, 2, 3, 4]);
+    for i in 0..4 {
+        // SAFETY: `i` is less than the number of lanes.
+        unsafe {
+            assert_eq!(*q.get_unchecked(i), q[i]);
+            *q.get_unchecked_mut(i) *= 10;
+        }
+    }
+    assert_eq!(q.into_inner(), [10, 20, 30, 40]);
+
+    let d = Double::new([1.5f32, 2.5]);
+    // SAFETY: 1 is less than the number of lanes.
+    assert_eq!(unsafe { *d.get_unchecked(1) }, 2.5);
+}
+
+#[test]
+fn into_array() {
+    fn total(values: impl Into<[i32; 4]>) -> i32 {
+        values.into().iter().sum()
+    }
+
+    assert_eq!(<[i32; 4]>::from(Quad::new([1, 2, 3, 4])), [1, 2, 3, 4]);
+    assert_eq!(total(Quad::new([1, 2, 3, 4])), 10);
+
+    let array: [f32; 2] = Double::new([1.0, 2.0]).into();
+    assert_eq!(array, [1.0, 2.0]);
+}
+
+#[test]
+fn splat_pair() {
+    assert_eq!(Quad::splat_pair(1, 2).into_inner(), [1, 2, 1, 2]);
+    assert_eq!(
+        Quad::splat_pair(0.5f32, -1.0).into_inner(),
+        [0.5, -1.0, 0.5, -1.0]
+    );
+}
+
+#[test]
+fn abs_neg_sign_bit() {
+    // Both operations must only touch the sign bit, including for NaN payloads and zeroes.
+    let lanes = [-0.0f32, f32::from_bits(0xffc0_0001), -1.5, f32::INFINITY];
+    let q = Quad::new(lanes);
+    let (abs, neg) = (q.abs(), -q);
+
+    for i in 0..4 {
+        assert_eq!(abs[i].to_bits(), lanes[i].to_bits() & 0x7fff_ffff);
+        assert_eq!(neg[i].to_bits(), lanes[i].to_bits() ^ 0x8000_0000);
+        assert_eq!(q.abs_real()[i].to_bits(), abs[i].to_bits());
+    }
+}
+
+#[test]
+fn min_max_parity() {
+    let a = [1.5f32, -0.5, 3.0, f32::NEG_INFINITY];
+    let b = [2.5f32, -1.0, 3.0, 7.0];
+    let (qa, qb) = (Quad::new(a), Quad::new(b));
+    for i in 0..4 {
+        assert_eq!(qa.min(qb)[i], a[i].min(b[i]));
+        assert_eq!(qa.max(qb)[i], a[i].max(b[i]));
+    }
+
+    let a = [i32::MIN, -1, 5, 9];
+    let b = [0, -2, 5, i32::MAX];
+    let (qa, qb) = (Quad::new(a), Quad::new(b));
+    for i in 0..4 {
+        assert_eq!(qa.min(qb)[i], a[i].min(b[i]));
+        assert_eq!(qa.max(qb)[i], a[i].max(b[i]));
+    }
+
+    let (ua, ub) = (Double::new([u32::MAX, 3]), Double::new([0u32, 4]));
+    assert_eq!(ua.min(ub).into_inner(), [0, 3]);
+    assert_eq!(ua.max(ub).into_inner(), [u32::MAX, 4]);
+
+    // Without SIMD, a NaN in either lane picks `other`, like `_mm_min_ps`.
+    #[cfg(not(feature = "nightly"))]
+    {
+        let nan = Double::new([f32::NAN, 1.0]);
+        let one = Double::new([1.0f32, f32::NAN]);
+        assert_eq!(nan.min(one)[0], 1.0);
+        assert!(nan.min(one)[1].is_nan());
+        assert_eq!(nan.max(one)[0], 1.0);
+        assert!(nan.max(one)[1].is_nan());
+    }
+}
+
+#[test]
+fn windows2_segments() {
+    let points = [
+        Double::new([0.0f32, 1.0]),
+        Double::new([2.0, 3.0]),
+        Double::new([4.0, 5.0]),
+    ];
+    let segments: Vec<_> = windows2(&points).map(Quad::into_inner).collect();
+    assert_eq!(segments, [[0.0, 1.0, 2.0, 3.0], [2.0, 3.0, 4.0, 5.0]]);
+
+    assert_eq!(windows2(&points[..1]).count(), 0);
+    assert_eq!(windows2::<i32>(&[]).count(), 0);
+}
+
+#[test]
+fn powi() {
+    assert_eq!(Quad::splat(2.0f32).powi(-2).into_inner(), [0.25; 4]);
+
+    let q = Quad::new([2.0f64, -3.0, 0.5, 10.0]);
+    assert_eq!(q.powi(3).into_inner(), [8.0, -27.0, 0.125, 1000.0]);
+    assert_eq!(q.powi(0).into_inner(), [1.0; 4]);
+    assert_eq!(q.powi(1), q);
+
+    let zero = Double::new([0.0f32, -0.0]).powi(-1);
+    assert_eq!(zero.into_inner(), [f32::INFINITY, f32::NEG_INFINITY]);
+    assert_eq!(Double::new([2.0f64, 1.0]).powi(i32::MIN)[1], 1.0);
+}
+
+#[test]
+fn clamp_reporting() {
+    let (min, max) = (Quad::splat(0.0f32), Quad::splat(1.0));
+
+    let (clamped, mask) = Quad::new([0.0, 0.25, 0.5, 1.0]).clamp_reporting(min, max);
+    assert_eq!(clamped.into_inner(), [0.0, 0.25, 0.5, 1.0]);
+    assert!(!mask.any());
+
+    let (clamped, mask) = Quad::new([-1.0, 0.5, 2.0, f32::NAN]).clamp_reporting(min, max);
+    assert_eq!(&clamped.into_inner()[..3], &[0.0, 0.5, 1.0]);
+    assert_eq!(mask.into_inner(), [true, false, true, true]);
+
+    let (clamped, mask) = Double::new([5, -5]).clamp_reporting(Double::splat(-2), Double::splat(2));
+    assert_eq!(clamped.into_inner(), [2, -2]);
+    assert!(mask.all());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_masks() {
+    let mask = QuadMask::<f32>::new([true, false, true, true]);
+
+    let bytes = bincode::serialize(&mask).unwrap();
+    assert_eq!(bytes, [0b1101]);
+    assert_eq!(bincode::deserialize::<QuadMask<f32>>(&bytes).unwrap(), mask);
+    assert!(bincode::deserialize::<QuadMask<f32>>(&[0b1_0000]).is_err());
+
+    let json = serde_json::to_string(&mask).unwrap();
+    assert_eq!(json, "[true,false,true,true]");
+    assert_eq!(serde_json::from_str::<QuadMask<f32>>(&json).unwrap(), mask);
+
+    let mask = DoubleMask::<i32>::new([false, true]);
+    let bytes = bincode::serialize(&mask).unwrap();
+    assert_eq!(
+        bincode::deserialize::<DoubleMask<i32>>(&bytes).unwrap(),
+        mask
+    );
+    let json = serde_json::to_string(&mask).unwrap();
+    assert_eq!(
+        serde_json::from_str::<DoubleMask<i32>>(&json).unwrap(),
+        mask
+    );
+
+    let quad = Quad::new([1.5f32, -2.0, 0.0, 4.0]);
+    let json = serde_json::to_string(&quad).unwrap();
+    assert_eq!(serde_json::from_str::<Quad<f32>>(&json).unwrap(), quad);
+    let bytes = bincode::serialize(&quad).unwrap();
+    assert_eq!(bincode::deserialize::<Quad<f32>>(&bytes).unwrap(), quad);
+}
+
+#[test]
+fn reduce_sum_nan_as_zero() {
+    let q = Quad::new([1.0f32, f32::NAN, 3.0, f32::NAN]);
+    assert_eq!(q.reduce_sum_nan_as_zero(), 4.0);
+    assert!(q.reduce_sum().is_nan());
+
+    assert_eq!(Double::<f64>::splat(f64::NAN).reduce_sum_nan_as_zero(), 0.0);
+    assert_eq!(
+        Double::new([f64::INFINITY, f64::NAN]).reduce_sum_nan_as_zero(),
+        f64::INFINITY
+    );
+}
+
+#[test]
+fn count_ones() {
+    let lanes = [0u32, u32::MAX, 0x8000_0001, 0x1234_5678];
+    let counts = Quad::new(lanes).count_ones();
+    for i in 0..4 {
+        assert_eq!(counts[i], lanes[i].count_ones());
+    }
+
+    let lanes = [-1i32, i32::MIN, 7, -0x1234_5678];
+    let counts = Quad::new(lanes).count_ones();
+    for i in 0..4 {
+        assert_eq!(counts[i], lanes[i].count_ones());
+    }
+}
+
+#[test]
+fn widening_from() {
+    let wide = Quad::<u64>::from(Quad::<u32>::splat(u32::MAX));
+    assert_eq!(wide.into_inner(), [u64::from(u32::MAX); 4]);
+
+    let wide: Quad<i32> = Quad::new([i16::MIN, -1, 0, i16::MAX]).into();
+    assert_eq!(
+        wide.into_inner(),
+        [i32::from(i16::MIN), -1, 0, i32::from(i16::MAX)]
+    );
+
+    let wide: Double<f64> = Double::new([0.1f32, -3.5]).into();
+    assert_eq!(wide.into_inner(), [f64::from(0.1f32), -3.5]);
+
+    let wide: Double<i16> = Double::new([255u8, 0]).into();
+    assert_eq!(wide.into_inner(), [255, 0]);
+}
+
+#[test]
+fn mix() {
+    let mixed = Quad::splat(0.0f32).mix(Quad::splat(10.0), Quad::new([0.0, 0.25, 0.5, 1.0]));
+    assert_eq!(mixed.into_inner(), [0.0, 2.5, 5.0, 10.0]);
+
+    let a = Double::new([1.0f64, -4.0]);
+    let b = Double::new([3.0, 4.0]);
+    assert_eq!(a.mix(b, Double::new([0.5, 0.75])).into_inner(), [2.0, 2.0]);
+
+    let exact = Double::splat(1e20f32).mix(Double::splat(1.0), Double::splat(1.0));
+    assert_eq!(exact.into_inner(), [1.0, 1.0]);
+}
+
+#[test]
+fn step() {
+    let stepped = Quad::new([0.0f32, 0.5, 1.0, 2.0]).step(Quad::splat(1.0));
+    assert_eq!(stepped.into_inner(), [0.0, 0.0, 1.0, 1.0]);
+    assert_eq!(
+        stepped,
+        Quad::new([0.0f32, 0.5, 1.0, 2.0])
+            .packed_ge(Quad::splat(1.0))
+            .to_quad()
+    );
+
+    let stepped = Double::new([f64::NAN, -1.0]).step(Double::new([0.0, -2.0]));
+    assert_eq!(stepped.into_inner(), [0.0, 1.0]);
+}